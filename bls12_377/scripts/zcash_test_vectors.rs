@@ -0,0 +1,62 @@
+//! Generates the G1 test vectors of the Zcash encoding in
+//! `src/curves/tests/g1_{compressed,uncompressed}_valid_test_vectors.dat`: the
+//! encodings of 0, G, 2G, ..., 999G.
+//!
+//! The points are computed with lambdaworks-math 0.7.0
+//! (https://github.com/lambdaclass/lambdaworks, crate `lambdaworks-math`,
+//! commit f6dda1c526c49a33809684c44a948e20dadf5a78), which implements BLS12-377
+//! G1 independently of arkworks. The compressed encoding follows
+//! `compress_g1_point` of its BLS12-381 module. To run it, add it as
+//! `src/main.rs` of a binary crate depending on `lambdaworks-math = "=0.7.0"`
+//! and run `cargo run` in `src/curves/tests`.
+
+use std::fs;
+
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::bls12_377::curve::BLS12377Curve, point::ShortWeierstrassProjectivePoint,
+        },
+        traits::IsEllipticCurve,
+    },
+    traits::ByteConversion,
+};
+
+type G1Point = ShortWeierstrassProjectivePoint<BLS12377Curve>;
+
+fn main() {
+    let g = BLS12377Curve::generator();
+    let mut p = G1Point::neutral_element();
+    let mut compressed = vec![];
+    let mut uncompressed = vec![];
+    for _ in 0..1000 {
+        if p == G1Point::neutral_element() {
+            // The infinity flag, and the compression flag when compressed.
+            let mut bytes = vec![0u8; 96];
+            bytes[0] |= 1 << 6;
+            uncompressed.extend_from_slice(&bytes);
+            bytes.truncate(48);
+            bytes[0] |= 1 << 7;
+            compressed.extend_from_slice(&bytes);
+        } else {
+            let affine = p.to_affine();
+            let (x, y) = (affine.x(), affine.y());
+            let mut x_bytes = x.to_bytes_be();
+            assert_eq!(x_bytes.len(), 48);
+            uncompressed.extend_from_slice(&x_bytes);
+            uncompressed.extend_from_slice(&y.to_bytes_be());
+
+            // The compression flag, and the sort flag if y is the
+            // lexicographically largest of y and -y.
+            x_bytes[0] |= 1 << 7;
+            if (-y).representative() < y.representative() {
+                x_bytes[0] |= 1 << 5;
+            }
+            compressed.extend_from_slice(&x_bytes);
+        }
+        p = p.operate_with(&g);
+    }
+    fs::write("g1_compressed_valid_test_vectors.dat", compressed).unwrap();
+    fs::write("g1_uncompressed_valid_test_vectors.dat", uncompressed).unwrap();
+}
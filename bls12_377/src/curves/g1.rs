@@ -1,3 +1,4 @@
+use ark_curve_utils::scalar_mul::double_and_add;
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
@@ -8,7 +9,7 @@ use ark_ec::{
             Affine as TEAffine, MontCurveConfig, Projective as TEProjective, TECurveConfig,
         },
     },
-    CurveConfig,
+    AffineRepr, CurveConfig,
 };
 use ark_ff::{Field, MontFp, PrimeField, Zero};
 use ark_std::{ops::Neg, One};
@@ -49,6 +50,25 @@ impl SWCurveConfig for Config {
         Self::BaseField::zero()
     }
 
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1SWAffine) -> bool {
+        // Algorithm from Section 6 of https://eprint.iacr.org/2021/1130.
+        //
+        // Check that endomorphism_p(P) == -[X^2]P
+
+        // An early-out optimization described in Section 6.
+        // If uP == P but P != point of infinity, then the point is not in the right
+        // subgroup.
+        let x_times_p = double_and_add(&p.into_group(), crate::Config::X);
+        if x_times_p.eq(p) && !p.infinity {
+            return false;
+        }
+
+        let minus_x_squared_times_p = double_and_add(&x_times_p, crate::Config::X).neg();
+        let endomorphism_p = endomorphism(p);
+        minus_x_squared_times_p.eq(&endomorphism_p)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G1SWAffine) -> G1SWAffine {
         // Using the effective cofactor.
@@ -181,6 +201,19 @@ pub const G1_GENERATOR_X: Fq = MontFp!("8193799937315096423993825557346594823998
 /// 241266749859715473739788878240585681733927191168601896383759122102112907357779751001206799952863815012735208165030
 pub const G1_GENERATOR_Y: Fq = MontFp!("241266749859715473739788878240585681733927191168601896383759122102112907357779751001206799952863815012735208165030");
 
+/// BETA is a non-trivial cubic root of unity in Fq, such that the
+/// endomorphism acts on G1 as the multiplication by -X^2.
+pub const BETA: Fq = MontFp!("258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231");
+
+pub fn endomorphism(p: &G1SWAffine) -> G1SWAffine {
+    // Endomorphism of the points on the curve.
+    // endomorphism_p(x,y) = (BETA * x, y)
+    // where BETA is a non-trivial cubic root of unity in Fq.
+    let mut res = *p;
+    res.x *= BETA;
+    res
+}
+
 impl WBConfig for Config {
     type IsogenousCurve = SwuIsoConfig;
 
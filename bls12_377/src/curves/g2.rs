@@ -1,3 +1,7 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+};
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
@@ -67,6 +71,21 @@ impl SWCurveConfig for Config {
         Self::BaseField::zero()
     }
 
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        // Algorithm from Section 4 of https://eprint.iacr.org/2021/1130.
+        //
+        // Checks that [p]P = [X]P
+
+        let mut x_times_point = double_and_add(&point.into_group(), crate::Config::X);
+        if crate::Config::X_IS_NEGATIVE {
+            x_times_point = -x_times_point;
+        }
+
+        let p_times_point = p_power_endomorphism(point);
+
+        x_times_point.eq(&p_times_point)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
         // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
@@ -76,7 +95,7 @@ impl SWCurveConfig for Config {
         let p_projective = p.into_group();
 
         // [x]P
-        let x_p = Config::mul_affine(p, x);
+        let x_p = double_and_add(&p_projective, x);
         // ψ(P)
         let psi_p = p_power_endomorphism(p);
         // (ψ^2)(2P)
@@ -88,7 +107,7 @@ impl SWCurveConfig for Config {
 
         // tmp2 = [x^2]P + [x]ψ(P)
         let mut tmp2: Projective<Config> = tmp;
-        tmp2 = tmp2.mul_bigint(x);
+        tmp2 = double_and_add(&tmp2, x);
 
         // add up all the terms
         psi2_p2 += tmp2;
@@ -96,6 +115,25 @@ impl SWCurveConfig for Config {
         psi2_p2 += &-psi_p;
         (psi2_p2 - p_projective).into_affine()
     }
+
+    #[inline]
+    fn mul_projective(p: &G2Projective, scalar: &[u64]) -> G2Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => glv::gls_mul(
+                p,
+                s,
+                crate::Config::X[0],
+                crate::Config::X_IS_NEGATIVE,
+                p_power_endomorphism_projective,
+            ),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    #[inline]
+    fn mul_affine(p: &G2Affine, scalar: &[u64]) -> G2Projective {
+        Self::mul_projective(&p.into_group(), scalar)
+    }
 }
 
 pub const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
@@ -164,6 +202,20 @@ fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
     res
 }
 
+/// psi on Jacobian coordinates. As the Frobenius map commutes with the
+/// divisions by Z^2 and Z^3, it is applied to Z as well.
+fn p_power_endomorphism_projective(p: &Projective<Config>) -> Projective<Config> {
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.y.frobenius_map_in_place(1);
+    res.z.frobenius_map_in_place(1);
+
+    res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
+    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
+
+    res
+}
+
 /// For a p-power endomorphism psi(P), compute psi(psi(P))
 fn double_p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
     // p_power_endomorphism(&p_power_endomorphism(&p.into_affine())).into()
@@ -7,6 +7,7 @@ use crate::*;
 
 pub mod g1;
 pub mod g2;
+pub mod zcash;
 
 mod g1_swu_iso;
 mod g2_swu_iso;
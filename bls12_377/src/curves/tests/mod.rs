@@ -1,13 +1,73 @@
-use crate::{Bls12_377, G1Projective, G2Projective};
+use crate::{
+    zcash::{ZcashG1Affine, ZcashG2Affine},
+    Bls12_377, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
 use ark_algebra_test_templates::*;
+use ark_curve_utils::test_glv;
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{fields::Field, One, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{rand::Rng, test_rng, vec};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_377>; msm);
 test_pairing!(pairing; crate::Bls12_377);
+test_glv!(g2_gls; crate::g2::Config; gls);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12377G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12377G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
 
+#[test]
+fn test_g1_endomorphism_beta() {
+    assert!(crate::g1::BETA.pow([3u64]).is_one());
+}
+
+#[test]
+fn test_g1_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G1Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g1_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G2Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_ec::{
@@ -55,3 +115,119 @@ mod test {
         wb_hash_arbitrary_string_to_curve::<crate::g2::Config>();
     }
 }
+
+// Checks the Zcash encodings of 0, G, 2G, ..., 999G, in the format of the
+// test vectors of https://github.com/zkcrypto/bls12_381. The G1 vectors are
+// computed with lambdaworks-math 0.7.0, an implementation of BLS12-377
+// independent of arkworks, by `scripts/zcash_test_vectors.rs`.
+macro_rules! test_zcash_vectors {
+    ($projective:ident, $wrapper:ident, $compress:expr, $expected:ident) => {
+        let mut e = $projective::zero();
+
+        let mut v = vec![];
+        {
+            let mut expected = $expected;
+            for _ in 0..1000 {
+                let e_affine = $wrapper(e.into_affine());
+                let mut serialized = vec![0u8; e_affine.serialized_size($compress)];
+                e_affine
+                    .serialize_with_mode(serialized.as_mut_slice(), $compress)
+                    .unwrap();
+                v.extend_from_slice(&serialized[..]);
+
+                let len_of_encoding = serialized.len();
+                let decoded = $wrapper::deserialize_with_mode(
+                    &expected[0..len_of_encoding],
+                    $compress,
+                    Validate::Yes,
+                )
+                .unwrap();
+                expected = &expected[len_of_encoding..];
+                assert_eq!(e_affine, decoded);
+
+                e += &$projective::generator();
+            }
+        }
+
+        assert_eq!(&v[..], $expected);
+    };
+}
+
+#[test]
+fn g1_zcash_compressed_valid_test_vectors() {
+    let bytes: &'static [u8] = include_bytes!("g1_compressed_valid_test_vectors.dat");
+    test_zcash_vectors!(G1Projective, ZcashG1Affine, Compress::Yes, bytes);
+}
+
+#[test]
+fn g1_zcash_uncompressed_valid_test_vectors() {
+    let bytes: &'static [u8] = include_bytes!("g1_uncompressed_valid_test_vectors.dat");
+    test_zcash_vectors!(G1Projective, ZcashG1Affine, Compress::No, bytes);
+}
+
+// lambdaworks-math does not implement BLS12-377 G2, so the G2 encodings are
+// only checked to round-trip.
+#[test]
+fn test_g2_zcash_round_trip() {
+    let mut e = G2Projective::zero();
+    for _ in 0..100 {
+        let e_affine = ZcashG2Affine(e.into_affine());
+        for compress in [Compress::Yes, Compress::No] {
+            let mut serialized = vec![];
+            e_affine
+                .serialize_with_mode(&mut serialized, compress)
+                .unwrap();
+            assert_eq!(serialized.len(), e_affine.serialized_size(compress));
+            let decoded =
+                ZcashG2Affine::deserialize_with_mode(&serialized[..], compress, Validate::Yes)
+                    .unwrap();
+            assert_eq!(e_affine, decoded);
+        }
+        e += &G2Projective::generator();
+    }
+}
+
+#[test]
+fn test_zcash_invalid_encodings() {
+    let g = ZcashG1Affine(G1Affine::generator());
+    let mut compressed = vec![];
+    g.serialize_compressed(&mut compressed).unwrap();
+    let mut uncompressed = vec![];
+    g.serialize_uncompressed(&mut uncompressed).unwrap();
+
+    // The compression flag must match the mode.
+    assert!(ZcashG1Affine::deserialize_uncompressed(&compressed[..]).is_err());
+    let mut bytes = uncompressed.clone();
+    bytes[0] |= 1 << 7;
+    assert!(ZcashG1Affine::deserialize_uncompressed(&bytes[..]).is_err());
+
+    // The sort flag is only allowed for compressed points.
+    let mut bytes = uncompressed.clone();
+    bytes[0] |= 1 << 5;
+    assert!(ZcashG1Affine::deserialize_uncompressed(&bytes[..]).is_err());
+
+    // The point at infinity must otherwise be zero.
+    let mut bytes = compressed.clone();
+    bytes[0] |= 1 << 6;
+    assert!(ZcashG1Affine::deserialize_compressed(&bytes[..]).is_err());
+
+    // The x-coordinate must be smaller than the modulus.
+    let mut bytes = [0xff; 48];
+    bytes[0] = 0b1001_1111;
+    assert!(ZcashG1Affine::deserialize_compressed(&bytes[..]).is_err());
+
+    // The default encoding is unchanged.
+    let mut default = vec![];
+    G1Affine::generator()
+        .serialize_compressed(&mut default)
+        .unwrap();
+    assert_ne!(default, compressed);
+    let mut bytes = vec![];
+    ZcashG2Affine(G2Affine::generator())
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert_eq!(
+        ZcashG2Affine::deserialize_compressed(&bytes[..]).unwrap().0,
+        G2Affine::generator()
+    );
+}
@@ -0,0 +1,228 @@
+//! The big-endian point encoding with three flag bits introduced by Zcash for
+//! BLS12-381, applied to BLS12-377. It is the encoding used by gnark-crypto,
+//! and by the Celo and Aleo tooling built on top of it.
+//!
+//! Field elements are encoded as 48-byte big-endian integers, an element
+//! `c0 + c1 * u` of Fq2 as `c1 || c0`. A compressed point is encoded as its
+//! x-coordinate, an uncompressed one as `x || y`. The three most significant
+//! bits of the first byte, which are always zero in the encoding of x, are
+//! used as flags:
+//! - the compression flag is set for compressed points;
+//! - the infinity flag is set for the point at infinity, whose encoding is
+//!   otherwise zero;
+//! - the sort flag is set for compressed points whose y-coordinate is the
+//!   lexicographically largest of `y` and `-y`.
+//!
+//! The default serialization of [`G1Affine`] and [`G2Affine`] is unchanged.
+//! The encoding is instead provided by the [`ZcashG1Affine`] and
+//! [`ZcashG2Affine`] wrappers.
+
+use ark_curve_utils::zcash::{deserialize_fq, serialize_fq, EncodingFlags};
+use ark_ec::AffineRepr;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+use crate::{Fq2, G1Affine, G2Affine};
+
+pub const G1_SERIALIZED_SIZE: usize = 48;
+pub const G2_SERIALIZED_SIZE: usize = 96;
+
+/// A G1 point that is serialized with the Zcash encoding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ZcashG1Affine(pub G1Affine);
+
+/// A G2 point that is serialized with the Zcash encoding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ZcashG2Affine(pub G2Affine);
+
+impl From<G1Affine> for ZcashG1Affine {
+    fn from(p: G1Affine) -> Self {
+        Self(p)
+    }
+}
+
+impl From<ZcashG1Affine> for G1Affine {
+    fn from(p: ZcashG1Affine) -> Self {
+        p.0
+    }
+}
+
+impl From<G2Affine> for ZcashG2Affine {
+    fn from(p: G2Affine) -> Self {
+        Self(p)
+    }
+}
+
+impl From<ZcashG2Affine> for G2Affine {
+    fn from(p: ZcashG2Affine) -> Self {
+        p.0
+    }
+}
+
+/// The size of a point whose compressed encoding has `size` bytes.
+fn encoded_size(size: usize, compress: Compress) -> usize {
+    match compress {
+        Compress::Yes => size,
+        Compress::No => 2 * size,
+    }
+}
+
+/// Reads the flags from `bytes` and masks them away. The point at infinity is
+/// rejected unless all its other bits are zero.
+fn read_flags(bytes: &mut [u8], compress: Compress) -> Result<EncodingFlags, SerializationError> {
+    let flags = EncodingFlags::get_flags(bytes);
+    bytes[0] &= 0b0001_1111;
+
+    if flags.is_compressed != (compress == Compress::Yes) {
+        return Err(SerializationError::UnexpectedFlags);
+    }
+    if (flags.is_lexicographically_largest && (!flags.is_compressed || flags.is_infinity))
+        || (flags.is_infinity && bytes.iter().any(|b| *b != 0))
+    {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(flags)
+}
+
+impl CanonicalSerialize for ZcashG1Affine {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let p = self.0;
+        let encoding = EncodingFlags {
+            is_compressed: compress == Compress::Yes,
+            is_infinity: p.is_zero(),
+            is_lexicographically_largest: p.y > -p.y,
+        };
+
+        let mut bytes = [0u8; 2 * G1_SERIALIZED_SIZE];
+        if let Some((x, y)) = p.xy() {
+            bytes[..48].copy_from_slice(&serialize_fq(*x));
+            bytes[48..].copy_from_slice(&serialize_fq(*y));
+        }
+        let bytes = &mut bytes[..self.serialized_size(compress)];
+        encoding.encode_flags(bytes);
+        writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        encoded_size(G1_SERIALIZED_SIZE, compress)
+    }
+}
+
+impl Valid for ZcashG1Affine {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl CanonicalDeserialize for ZcashG1Affine {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; 2 * G1_SERIALIZED_SIZE];
+        let bytes = &mut bytes[..encoded_size(G1_SERIALIZED_SIZE, compress)];
+        reader.read_exact(bytes)?;
+
+        let flags = read_flags(bytes, compress)?;
+        if flags.is_infinity {
+            return Ok(Self(G1Affine::zero()));
+        }
+
+        let x = deserialize_fq(&bytes[..48])?;
+        let p = if flags.is_compressed {
+            G1Affine::get_point_from_x_unchecked(x, flags.is_lexicographically_largest)
+                .ok_or(SerializationError::InvalidData)?
+        } else {
+            G1Affine::new_unchecked(x, deserialize_fq(&bytes[48..])?)
+        };
+
+        let p = Self(p);
+        if validate == Validate::Yes {
+            p.check()?;
+        }
+        Ok(p)
+    }
+}
+
+impl CanonicalSerialize for ZcashG2Affine {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let p = self.0;
+        let encoding = EncodingFlags {
+            is_compressed: compress == Compress::Yes,
+            is_infinity: p.is_zero(),
+            is_lexicographically_largest: p.y > -p.y,
+        };
+
+        let mut bytes = [0u8; 2 * G2_SERIALIZED_SIZE];
+        if let Some((x, y)) = p.xy() {
+            bytes[..48].copy_from_slice(&serialize_fq(x.c1));
+            bytes[48..96].copy_from_slice(&serialize_fq(x.c0));
+            bytes[96..144].copy_from_slice(&serialize_fq(y.c1));
+            bytes[144..].copy_from_slice(&serialize_fq(y.c0));
+        }
+        let bytes = &mut bytes[..self.serialized_size(compress)];
+        encoding.encode_flags(bytes);
+        writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        encoded_size(G2_SERIALIZED_SIZE, compress)
+    }
+}
+
+impl Valid for ZcashG2Affine {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl CanonicalDeserialize for ZcashG2Affine {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; 2 * G2_SERIALIZED_SIZE];
+        let bytes = &mut bytes[..encoded_size(G2_SERIALIZED_SIZE, compress)];
+        reader.read_exact(bytes)?;
+
+        let flags = read_flags(bytes, compress)?;
+        if flags.is_infinity {
+            return Ok(Self(G2Affine::zero()));
+        }
+
+        let x = Fq2::new(
+            deserialize_fq(&bytes[48..96])?,
+            deserialize_fq(&bytes[..48])?,
+        );
+        let p = if flags.is_compressed {
+            G2Affine::get_point_from_x_unchecked(x, flags.is_lexicographically_largest)
+                .ok_or(SerializationError::InvalidData)?
+        } else {
+            let y = Fq2::new(
+                deserialize_fq(&bytes[144..])?,
+                deserialize_fq(&bytes[96..144])?,
+            );
+            G2Affine::new_unchecked(x, y)
+        };
+
+        let p = Self(p);
+        if validate == Validate::Yes {
+            p.check()?;
+        }
+        Ok(p)
+    }
+}
@@ -0,0 +1,284 @@
+//! The byte encodings of [EIP-2537], and emulations of the BLS12-381
+//! precompiles of Ethereum defined there.
+//!
+//! Field elements are encoded as 64-byte big-endian integers, which must be
+//! smaller than the modulus, and whose top 16 bytes must therefore be zero. An
+//! element `c0 + c1 * u` of Fq2 is encoded as `c0 || c1`, a point as `x || y`,
+//! and the point at infinity as all zeros. Scalars are encoded as 32-byte
+//! big-endian integers, which are not required to be smaller than r.
+//!
+//! Every decoded point is checked to be on the curve. The `G1MSM`, `G2MSM`
+//! and `PAIRING_CHECK` precompiles additionally check that the points are in
+//! the prime-order subgroup, while `G1ADD` and `G2ADD` do not.
+//!
+//! The precompiles return an error exactly when the corresponding Ethereum
+//! call fails, and the [`Error`] tells why. They do not charge gas.
+//!
+//! [EIP-2537]: https://eips.ethereum.org/EIPS/eip-2537
+
+use ark_ec::{
+    hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurve},
+    pairing::Pairing,
+    scalar_mul::variable_base::VariableBaseMSM,
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{BigInteger384, One, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+use crate::{g1, g2, Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+pub const FQ_ENCODED_SIZE: usize = 64;
+pub const FQ2_ENCODED_SIZE: usize = 2 * FQ_ENCODED_SIZE;
+pub const G1_ENCODED_SIZE: usize = 2 * FQ_ENCODED_SIZE;
+pub const G2_ENCODED_SIZE: usize = 2 * FQ2_ENCODED_SIZE;
+pub const SCALAR_ENCODED_SIZE: usize = 32;
+
+const FQ_PADDING_SIZE: usize = 16;
+const G1_MSM_PAIR_SIZE: usize = G1_ENCODED_SIZE + SCALAR_ENCODED_SIZE;
+const G2_MSM_PAIR_SIZE: usize = G2_ENCODED_SIZE + SCALAR_ENCODED_SIZE;
+const PAIRING_CHECK_PAIR_SIZE: usize = G1_ENCODED_SIZE + G2_ENCODED_SIZE;
+
+/// The reasons for which the decoding of an input, and therefore a
+/// precompile, fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input of a precompile does not have a valid length.
+    InvalidInputLength,
+    /// The top 16 bytes of an encoded field element are not zero.
+    InvalidFieldElementTopBytes,
+    /// An encoded field element is not smaller than p.
+    InvalidFieldElement,
+    /// A point is not on the curve.
+    PointNotOnCurve,
+    /// A point is not in the prime-order subgroup.
+    PointNotInSubgroup,
+}
+
+/// Decodes a 64-byte big-endian integer, which must be smaller than p.
+pub fn decode_fq(bytes: &[u8; FQ_ENCODED_SIZE]) -> Result<Fq, Error> {
+    let (padding, bytes) = bytes.split_at(FQ_PADDING_SIZE);
+    if padding.iter().any(|b| *b != 0) {
+        return Err(Error::InvalidFieldElementTopBytes);
+    }
+
+    let mut tmp = BigInteger384::zero();
+    for (limb, chunk) in tmp.0.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Fq::from_bigint(tmp).ok_or(Error::InvalidFieldElement)
+}
+
+/// Encodes a field element as a 64-byte big-endian integer.
+pub fn encode_fq(field: Fq) -> [u8; FQ_ENCODED_SIZE] {
+    let rep = field.into_bigint();
+    let mut result = [0u8; FQ_ENCODED_SIZE];
+    for (chunk, limb) in result[FQ_PADDING_SIZE..]
+        .chunks_exact_mut(8)
+        .zip(rep.0.iter().rev())
+    {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    result
+}
+
+/// Decodes an element `c0 + c1 * u` of Fq2 from `c0 || c1`.
+pub fn decode_fq2(bytes: &[u8; FQ2_ENCODED_SIZE]) -> Result<Fq2, Error> {
+    let c0 = decode_fq(bytes[..64].try_into().unwrap())?;
+    let c1 = decode_fq(bytes[64..].try_into().unwrap())?;
+    Ok(Fq2::new(c0, c1))
+}
+
+/// Encodes an element `c0 + c1 * u` of Fq2 as `c0 || c1`.
+pub fn encode_fq2(field: Fq2) -> [u8; FQ2_ENCODED_SIZE] {
+    let mut result = [0u8; FQ2_ENCODED_SIZE];
+    result[..64].copy_from_slice(&encode_fq(field.c0));
+    result[64..].copy_from_slice(&encode_fq(field.c1));
+    result
+}
+
+/// Decodes a G1 point, checking that it is on the curve, but not that it is in
+/// the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8; G1_ENCODED_SIZE]) -> Result<G1Affine, Error> {
+    let x = decode_fq(bytes[..64].try_into().unwrap())?;
+    let y = decode_fq(bytes[64..].try_into().unwrap())?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+
+    let p = G1Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::PointNotOnCurve);
+    }
+    Ok(p)
+}
+
+/// Encodes a G1 point as `x || y`, and the point at infinity as all zeros.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_ENCODED_SIZE] {
+    let mut result = [0u8; G1_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        result[..64].copy_from_slice(&encode_fq(*x));
+        result[64..].copy_from_slice(&encode_fq(*y));
+    }
+    result
+}
+
+/// Decodes a G2 point, checking that it is on the curve, but not that it is in
+/// the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8; G2_ENCODED_SIZE]) -> Result<G2Affine, Error> {
+    let x = decode_fq2(bytes[..128].try_into().unwrap())?;
+    let y = decode_fq2(bytes[128..].try_into().unwrap())?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+
+    let p = G2Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::PointNotOnCurve);
+    }
+    Ok(p)
+}
+
+/// Encodes a G2 point as `x || y`, and the point at infinity as all zeros.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_ENCODED_SIZE] {
+    let mut result = [0u8; G2_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        result[..128].copy_from_slice(&encode_fq2(*x));
+        result[128..].copy_from_slice(&encode_fq2(*y));
+    }
+    result
+}
+
+/// The `G1ADD` precompile at address 0x0b.
+///
+/// The input must be exactly 256 bytes long. The points are not required to
+/// be in the prime-order subgroup.
+pub fn g1_add(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let input: &[u8; 2 * G1_ENCODED_SIZE] =
+        input.try_into().map_err(|_| Error::InvalidInputLength)?;
+    let p = decode_g1(input[..128].try_into().unwrap())?;
+    let q = decode_g1(input[128..].try_into().unwrap())?;
+    Ok(encode_g1(&(p + q).into_affine()))
+}
+
+/// The `G1MSM` precompile at address 0x0c.
+///
+/// The input is a non-empty sequence of pairs of a G1 point, which must be in
+/// the prime-order subgroup, and a scalar.
+pub fn g1_msm(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let (bases, scalars) = decode_msm_input::<G1_MSM_PAIR_SIZE, _>(input, |bytes| {
+        decode_g1(bytes.try_into().unwrap()).and_then(check_subgroup)
+    })?;
+    // `msm` goes through `SWCurveConfig::msm`, which is the GLV MSM on G1,
+    // and `decode_msm_input` returns as many bases as scalars.
+    let p = G1Projective::msm(&bases, &scalars).unwrap();
+    Ok(encode_g1(&p.into_affine()))
+}
+
+/// The `G2ADD` precompile at address 0x0d.
+///
+/// The input must be exactly 512 bytes long. The points are not required to
+/// be in the prime-order subgroup.
+pub fn g2_add(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    let input: &[u8; 2 * G2_ENCODED_SIZE] =
+        input.try_into().map_err(|_| Error::InvalidInputLength)?;
+    let p = decode_g2(input[..256].try_into().unwrap())?;
+    let q = decode_g2(input[256..].try_into().unwrap())?;
+    Ok(encode_g2(&(p + q).into_affine()))
+}
+
+/// The `G2MSM` precompile at address 0x0e.
+///
+/// The input is a non-empty sequence of pairs of a G2 point, which must be in
+/// the prime-order subgroup, and a scalar.
+pub fn g2_msm(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    let (bases, scalars) = decode_msm_input::<G2_MSM_PAIR_SIZE, _>(input, |bytes| {
+        decode_g2(bytes.try_into().unwrap()).and_then(check_subgroup)
+    })?;
+    // As for G1, `decode_msm_input` returns as many bases as scalars.
+    let p = G2Projective::msm(&bases, &scalars).unwrap();
+    Ok(encode_g2(&p.into_affine()))
+}
+
+/// The `PAIRING_CHECK` precompile at address 0x0f.
+///
+/// The input is a non-empty sequence of pairs of a G1 and a G2 point, which
+/// must be in the prime-order subgroups. The output is 1 as a 32-byte
+/// big-endian integer if the product of the pairings is 1, and 0 otherwise.
+pub fn pairing_check(input: &[u8]) -> Result<[u8; 32], Error> {
+    let pairs = input.chunks_exact(PAIRING_CHECK_PAIR_SIZE);
+    if input.is_empty() || !pairs.remainder().is_empty() {
+        return Err(Error::InvalidInputLength);
+    }
+
+    let mut g1 = Vec::with_capacity(pairs.len());
+    let mut g2 = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        g1.push(decode_g1(pair[..128].try_into().unwrap()).and_then(check_subgroup)?);
+        g2.push(decode_g2(pair[128..].try_into().unwrap()).and_then(check_subgroup)?);
+    }
+
+    let mut result = [0u8; 32];
+    if Bls12_381::multi_pairing(g1, g2).0.is_one() {
+        result[31] = 1;
+    }
+    Ok(result)
+}
+
+/// The `MAP_FP_TO_G1` precompile at address 0x10.
+///
+/// The input is a single encoded field element, which is mapped to G1 as by
+/// the `encode_to_curve` function of the `BLS12381G1_XMD:SHA-256_SSWU_NU_`
+/// suite of RFC 9380, that is, by the SSWU map followed by cofactor clearing.
+pub fn map_fp_to_g1(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let u = decode_fq(input.try_into().map_err(|_| Error::InvalidInputLength)?)?;
+    // The SSWU map is defined on every element of the field.
+    let p = WBMap::<g1::Config>::new()
+        .and_then(|map| map.map_to_curve(u))
+        .unwrap();
+    Ok(encode_g1(&p.clear_cofactor()))
+}
+
+/// The `MAP_FP2_TO_G2` precompile at address 0x11.
+///
+/// The input is a single encoded element of Fq2, which is mapped to G2 as by
+/// the `encode_to_curve` function of the `BLS12381G2_XMD:SHA-256_SSWU_NU_`
+/// suite of RFC 9380, that is, by the SSWU map followed by cofactor clearing.
+pub fn map_fp2_to_g2(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    let u = decode_fq2(input.try_into().map_err(|_| Error::InvalidInputLength)?)?;
+    // The SSWU map is defined on every element of the field.
+    let p = WBMap::<g2::Config>::new()
+        .and_then(|map| map.map_to_curve(u))
+        .unwrap();
+    Ok(encode_g2(&p.clear_cofactor()))
+}
+
+fn check_subgroup<P: SWCurveConfig>(p: Affine<P>) -> Result<Affine<P>, Error> {
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::PointNotInSubgroup);
+    }
+    Ok(p)
+}
+
+/// Splits the input of an MSM precompile, a non-empty sequence of pairs of `N`
+/// bytes, into the points decoded by `decode_point` and the scalars.
+fn decode_msm_input<const N: usize, P>(
+    input: &[u8],
+    decode_point: impl Fn(&[u8]) -> Result<P, Error>,
+) -> Result<(Vec<P>, Vec<Fr>), Error> {
+    let pairs = input.chunks_exact(N);
+    if input.is_empty() || !pairs.remainder().is_empty() {
+        return Err(Error::InvalidInputLength);
+    }
+
+    let mut bases = Vec::with_capacity(pairs.len());
+    let mut scalars = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let (point, scalar) = pair.split_at(N - SCALAR_ENCODED_SIZE);
+        bases.push(decode_point(point)?);
+        // The points are in the prime-order subgroup, so that the scalars can
+        // be reduced modulo r.
+        scalars.push(Fr::from_be_bytes_mod_order(scalar));
+    }
+    Ok((bases, scalars))
+}
@@ -1,20 +1,24 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+    zcash::{serialize_fq, EncodingFlags},
+};
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
-    short_weierstrass::{Affine, SWCurveConfig},
-    AffineRepr, Group,
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr,
 };
-use ark_ff::{Field, MontFp, PrimeField, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 use ark_serialize::{Compress, SerializationError};
 use ark_std::{ops::Neg, One};
 
 use super::g1_swu_iso;
 use crate::{
-    util::{
-        read_g1_compressed, read_g1_uncompressed, serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
-    },
+    util::{read_g1_compressed, read_g1_uncompressed, G1_SERIALIZED_SIZE},
     Fq, Fr,
 };
 
@@ -61,12 +65,12 @@ impl SWCurveConfig for Config {
         // An early-out optimization described in Section 6.
         // If uP == P but P != point of infinity, then the point is not in the right
         // subgroup.
-        let x_times_p = p.mul_bigint(crate::Config::X);
+        let x_times_p = double_and_add(&p.into_group(), crate::Config::X);
         if x_times_p.eq(p) && !p.infinity {
             return false;
         }
 
-        let minus_x_squared_times_p = x_times_p.mul_bigint(crate::Config::X).neg();
+        let minus_x_squared_times_p = double_and_add(&x_times_p, crate::Config::X).neg();
         let endomorphism_p = endomorphism(p);
         minus_x_squared_times_p.eq(&endomorphism_p)
     }
@@ -78,7 +82,37 @@ impl SWCurveConfig for Config {
         //
         // It is enough to multiply by (1 - x), instead of (x - 1)^2 / 3
         let h_eff = one_minus_x().into_bigint();
-        Config::mul_affine(&p, h_eff.as_ref()).into()
+        double_and_add(&p.into_group(), h_eff.as_ref()).into()
+    }
+
+    /// Scalars that are elements of Fr are multiplied with the GLV method,
+    /// which is only valid for points of G1, the prime-order subgroup. Other
+    /// scalars, such as r or the cofactor, are multiplied with
+    /// [`double_and_add`], which is valid for every point of the curve.
+    #[inline]
+    fn mul_projective(p: &G1Projective, scalar: &[u64]) -> G1Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_projective(*p, s),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    /// As `mul_projective`, this is only valid for points of G1 when
+    /// `scalar` is an element of Fr.
+    #[inline]
+    fn mul_affine(p: &G1Affine, scalar: &[u64]) -> G1Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_affine(*p, s).into(),
+            None => double_and_add(&p.into_group(), scalar),
+        }
+    }
+
+    /// The halves of the GLV decomposition of the scalars have at most 128 bits.
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<G1Projective, usize> {
+        (bases.len() == scalars.len())
+            .then(|| glv::glv_msm(bases, scalars, 128))
+            .ok_or(bases.len().min(scalars.len()))
     }
 
     fn deserialize_with_mode<R: ark_serialize::Read>(
@@ -107,7 +141,7 @@ impl SWCurveConfig for Config {
         let encoding = EncodingFlags {
             is_compressed: compress == ark_serialize::Compress::Yes,
             is_infinity: item.is_zero(),
-            is_lexographically_largest: item.y > -item.y,
+            is_lexicographically_largest: item.y > -item.y,
         };
         let mut p = *item;
         if encoding.is_infinity {
@@ -147,6 +181,35 @@ fn one_minus_x() -> Fr {
     Fr::one() - X
 }
 
+impl GLVConfig for Config {
+    /// ENDO_COEFFS = [BETA]
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[BETA];
+
+    /// LAMBDA = -X^2 is the eigenvalue of the endomorphism on G1:
+    /// LAMBDA = 52435875175126190479447740508185965837461563690374988244538805122978187051009
+    const LAMBDA: Self::ScalarField =
+        MontFp!("52435875175126190479447740508185965837461563690374988244538805122978187051009");
+
+    /// SCALAR_DECOMP_COEFFS = [(1, 1 - X^2), (X^2, 1)], a reduced basis of the
+    /// lattice {(a, b) : a + b * LAMBDA = 0 mod r}
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("1")),
+        (false, BigInt!("228988810152649578064853576960394133503")),
+        (true, BigInt!("228988810152649578064853576960394133504")),
+        (true, BigInt!("1")),
+    ];
+
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        let mut res = *p;
+        res.x *= BETA;
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        endomorphism(p)
+    }
+}
+
 // Parameters from the [IETF draft v16, section E.2](https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-11-isogeny-map-for-bls12-381).
 impl WBConfig for Config {
     type IsogenousCurve = g1_swu_iso::SwuIsoConfig;
@@ -1,3 +1,8 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+    zcash::{serialize_fq, EncodingFlags},
+};
 use ark_std::ops::Neg;
 
 use ark_ec::{
@@ -11,10 +16,7 @@ use ark_ec::{
 use ark_ff::{Field, MontFp, Zero};
 use ark_serialize::{Compress, SerializationError};
 
-use super::{
-    g2_swu_iso,
-    util::{serialize_fq, EncodingFlags, G2_SERIALIZED_SIZE},
-};
+use super::{g2_swu_iso, util::G2_SERIALIZED_SIZE};
 use crate::{
     util::{read_g2_compressed, read_g2_uncompressed},
     *,
@@ -71,7 +73,7 @@ impl SWCurveConfig for Config {
         //
         // Checks that [p]P = [X]P
 
-        let mut x_times_point = point.mul_bigint(crate::Config::X);
+        let mut x_times_point = double_and_add(&point.into_group(), crate::Config::X);
         if crate::Config::X_IS_NEGATIVE {
             x_times_point = -x_times_point;
         }
@@ -94,7 +96,7 @@ impl SWCurveConfig for Config {
         let p_projective = p.into_group();
 
         // [x]P
-        let x_p = Config::mul_affine(p, &x).neg();
+        let x_p = double_and_add(&p_projective, x).neg();
         // ψ(P)
         let psi_p = p_power_endomorphism(&p);
         // (ψ^2)(2P)
@@ -106,7 +108,7 @@ impl SWCurveConfig for Config {
 
         // tmp2 = [x^2]P + [x]ψ(P)
         let mut tmp2: Projective<Config> = tmp;
-        tmp2 = tmp2.mul_bigint(x).neg();
+        tmp2 = double_and_add(&tmp2, x).neg();
 
         // add up all the terms
         psi2_p2 += tmp2;
@@ -115,6 +117,31 @@ impl SWCurveConfig for Config {
         (psi2_p2 - p_projective).into_affine()
     }
 
+    /// Scalars that are elements of Fr are multiplied with the GLS method,
+    /// which is only valid for points of G2, the prime-order subgroup. Other
+    /// scalars, such as r or the cofactor, are multiplied with
+    /// [`double_and_add`], which is valid for every point of the curve.
+    #[inline]
+    fn mul_projective(p: &G2Projective, scalar: &[u64]) -> G2Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => glv::gls_mul(
+                p,
+                s,
+                crate::Config::X[0],
+                crate::Config::X_IS_NEGATIVE,
+                p_power_endomorphism_projective,
+            ),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    /// As `mul_projective`, this is only valid for points of G2 when
+    /// `scalar` is an element of Fr.
+    #[inline]
+    fn mul_affine(p: &G2Affine, scalar: &[u64]) -> G2Projective {
+        Self::mul_projective(&p.into_group(), scalar)
+    }
+
     fn deserialize_with_mode<R: ark_serialize::Read>(
         mut reader: R,
         compress: ark_serialize::Compress,
@@ -141,7 +168,7 @@ impl SWCurveConfig for Config {
         let encoding = EncodingFlags {
             is_compressed: compress == ark_serialize::Compress::Yes,
             is_infinity: item.is_zero(),
-            is_lexographically_largest: item.y > -item.y,
+            is_lexicographically_largest: item.y > -item.y,
         };
         let mut p = *item;
         if encoding.is_infinity {
@@ -254,6 +281,20 @@ fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
     res
 }
 
+/// psi on Jacobian coordinates. As the Frobenius map commutes with the
+/// divisions by Z^2 and Z^3, it is applied to Z as well.
+fn p_power_endomorphism_projective(p: &Projective<Config>) -> Projective<Config> {
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.y.frobenius_map_in_place(1);
+    res.z.frobenius_map_in_place(1);
+
+    res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
+    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
+
+    res
+}
+
 /// For a p-power endomorphism psi(P), compute psi(psi(P))
 fn double_p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
     let mut res = *p;
@@ -302,6 +343,26 @@ mod test {
         assert_eq!(psi2_p_composed, psi2_p_optimised);
     }
 
+    #[test]
+    fn test_mul_outside_subgroup() {
+        use ark_ff::PrimeField;
+
+        // r and the cofactor are not elements of Fr, so that they are
+        // multiplied with double_and_add rather than with the GLS method.
+        let p = sample_unchecked();
+        assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+        for scalar in [Fr::MODULUS.as_ref(), g2::Config::COFACTOR] {
+            let naive = double_and_add(&p.into_group(), scalar);
+            assert_eq!(p.mul_bigint(scalar), naive);
+            assert_eq!(p.into_group().mul_bigint(scalar), naive);
+        }
+        assert!(!p.mul_bigint(Fr::MODULUS).is_zero());
+        assert!(p
+            .mul_bigint(g2::Config::COFACTOR)
+            .into_affine()
+            .is_in_correct_subgroup_assuming_on_curve());
+    }
+
     #[test]
     fn test_cofactor_clearing() {
         // multiplying by h_eff and clearing the cofactor by the efficient
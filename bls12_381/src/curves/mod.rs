@@ -2,6 +2,7 @@ use ark_ec::bls12::{Bls12, Bls12Config, TwistType};
 
 use crate::{Fq, Fq12Config, Fq2Config, Fq6Config};
 
+pub mod eip2537;
 pub mod g1;
 pub mod g2;
 pub(crate) mod util;
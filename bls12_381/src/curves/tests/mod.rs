@@ -1,15 +1,19 @@
 use ark_algebra_test_templates::*;
+use ark_curve_utils::test_glv;
 use ark_ec::{AffineRepr, CurveGroup, Group};
 use ark_ff::{fields::Field, One, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
-use ark_std::{rand::Rng, test_rng, vec};
+use ark_std::{format, fs::File, io::BufReader, rand::Rng, test_rng, vec};
+use serde_json::Value;
 
-use crate::{Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use crate::{eip2537, Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_381>; msm);
 test_pairing!(pairing; crate::Bls12_381);
+test_glv!(g1_glv; crate::g1::Config; glv);
+test_glv!(g2_gls; crate::g2::Config; gls);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12381G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
 
@@ -119,3 +123,138 @@ fn g2_uncompressed_valid_test_vectors() {
     let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
     test_vectors!(G2Projective, G2Affine, Compress::No, bytes);
 }
+
+/// Runs the precompile `f` on the vectors of `paths`, which follow the format
+/// of the test vectors of EIP-2537. The files prefixed with `eip2537_` and
+/// suffixed with `_bls` are the ones of the EIP. The failures are checked
+/// against their `ExpectedError`.
+fn test_eip2537_precompile<const N: usize>(
+    paths: &[&str],
+    f: fn(&[u8]) -> Result<[u8; N], eip2537::Error>,
+) {
+    for path in paths {
+        let file = File::open(format!("./src/curves/tests/eip2537_{}.json", path)).unwrap();
+        let vectors: Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+        for v in vectors.as_array().unwrap() {
+            let input = hex::decode(v["Input"].as_str().unwrap()).unwrap();
+            let name = v["Name"].as_str().unwrap();
+            match v.get("Expected") {
+                Some(expected) => assert_eq!(
+                    hex::encode(f(&input).unwrap()),
+                    expected.as_str().unwrap(),
+                    "{}",
+                    name
+                ),
+                None => {
+                    let error = match v["ExpectedError"].as_str().unwrap() {
+                        "invalid input length" => eip2537::Error::InvalidInputLength,
+                        "invalid field element top bytes" => {
+                            eip2537::Error::InvalidFieldElementTopBytes
+                        },
+                        "invalid fp.Element encoding" => eip2537::Error::InvalidFieldElement,
+                        "invalid point: not on curve" => eip2537::Error::PointNotOnCurve,
+                        "g1 point is not on correct subgroup"
+                        | "g2 point is not on correct subgroup" => {
+                            eip2537::Error::PointNotInSubgroup
+                        },
+                        e => panic!("unknown error {} of {}", e, name),
+                    };
+                    assert_eq!(f(&input), Err(error), "{}", name);
+                },
+            }
+        }
+    }
+}
+
+#[test]
+fn test_eip2537_g1_add() {
+    test_eip2537_precompile(
+        &["g1_add", "add_G1_bls", "fail-add_G1_bls"],
+        eip2537::g1_add,
+    );
+}
+
+// The multiplications of the earlier versions of EIP-2537 are MSMs of a single
+// pair.
+#[test]
+fn test_eip2537_g1_msm() {
+    test_eip2537_precompile(
+        &[
+            "g1_msm",
+            "multiexp_G1_bls",
+            "fail-multiexp_G1_bls",
+            "mul_G1_bls",
+            "fail-mul_G1_bls",
+        ],
+        eip2537::g1_msm,
+    );
+}
+
+#[test]
+fn test_eip2537_g2_add() {
+    test_eip2537_precompile(
+        &["g2_add", "add_G2_bls", "fail-add_G2_bls"],
+        eip2537::g2_add,
+    );
+}
+
+#[test]
+fn test_eip2537_g2_msm() {
+    test_eip2537_precompile(
+        &[
+            "g2_msm",
+            "multiexp_G2_bls",
+            "fail-multiexp_G2_bls",
+            "mul_G2_bls",
+            "fail-mul_G2_bls",
+        ],
+        eip2537::g2_msm,
+    );
+}
+
+#[test]
+fn test_eip2537_pairing_check() {
+    test_eip2537_precompile(
+        &[
+            "pairing_check",
+            "pairing_check_bls",
+            "fail-pairing_check_bls",
+        ],
+        eip2537::pairing_check,
+    );
+}
+
+#[test]
+fn test_eip2537_map_fp_to_g1() {
+    test_eip2537_precompile(
+        &["map_fp_to_g1", "map_fp_to_G1_bls", "fail-map_fp_to_G1_bls"],
+        eip2537::map_fp_to_g1,
+    );
+}
+
+#[test]
+fn test_eip2537_map_fp2_to_g2() {
+    test_eip2537_precompile(
+        &[
+            "map_fp2_to_g2",
+            "map_fp2_to_G2_bls",
+            "fail-map_fp2_to_G2_bls",
+        ],
+        eip2537::map_fp2_to_g2,
+    );
+}
+
+#[test]
+fn test_eip2537_encoding_round_trip() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip2537::decode_g1(&eip2537::encode_g1(&p)).unwrap(), p);
+        let q = G2Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip2537::decode_g2(&eip2537::encode_g2(&q)).unwrap(), q);
+    }
+    assert_eq!(eip2537::encode_g1(&G1Affine::zero()), [0u8; 128]);
+    assert_eq!(eip2537::encode_g2(&G2Affine::zero()), [0u8; 256]);
+    assert!(eip2537::decode_g1(&[0u8; 128]).unwrap().is_zero());
+    assert!(eip2537::decode_g2(&[0u8; 256]).unwrap().is_zero());
+}
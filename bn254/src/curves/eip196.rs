@@ -0,0 +1,162 @@
+//! The byte encodings of [EIP-196] and [EIP-197], and emulations of the
+//! `ecAdd`, `ecMul` and `ecPairing` precompiles of Ethereum defined there.
+//!
+//! Field elements are encoded as 32-byte big-endian integers, which must be
+//! smaller than the modulus. A G1 point is encoded as `x || y`, a G2 point as
+//! `x.c1 || x.c0 || y.c1 || y.c0`, and the point at infinity is encoded as
+//! all zeros in both cases.
+//!
+//! The precompiles return an error exactly when the corresponding Ethereum
+//! call fails. They do not charge gas.
+//!
+//! [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+//! [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInteger256, One, PrimeField, Zero};
+use ark_serialize::SerializationError;
+use ark_std::vec::Vec;
+
+use crate::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+
+pub const FQ_ENCODED_SIZE: usize = 32;
+pub const G1_ENCODED_SIZE: usize = 64;
+pub const G2_ENCODED_SIZE: usize = 128;
+
+const EC_ADD_INPUT_SIZE: usize = 2 * G1_ENCODED_SIZE;
+const EC_MUL_INPUT_SIZE: usize = G1_ENCODED_SIZE + 32;
+const EC_PAIRING_PAIR_SIZE: usize = G1_ENCODED_SIZE + G2_ENCODED_SIZE;
+
+/// Decodes a 32-byte big-endian integer, which must be smaller than p.
+pub fn decode_fq(bytes: &[u8; FQ_ENCODED_SIZE]) -> Result<Fq, SerializationError> {
+    Fq::from_bigint(read_u256(bytes)).ok_or(SerializationError::InvalidData)
+}
+
+/// Encodes a field element as a 32-byte big-endian integer.
+pub fn encode_fq(field: Fq) -> [u8; FQ_ENCODED_SIZE] {
+    let rep = field.into_bigint();
+    let mut result = [0u8; FQ_ENCODED_SIZE];
+    for (chunk, limb) in result.chunks_exact_mut(8).zip(rep.0.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    result
+}
+
+/// Decodes a G1 point, checking that it is on the curve. As the cofactor of
+/// G1 is 1, the point is then in the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8; G1_ENCODED_SIZE]) -> Result<G1Affine, SerializationError> {
+    let x = decode_fq(bytes[..32].try_into().unwrap())?;
+    let y = decode_fq(bytes[32..].try_into().unwrap())?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+
+    let p = G1Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(p)
+}
+
+/// Encodes a G1 point as `x || y`, and the point at infinity as all zeros.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_ENCODED_SIZE] {
+    let mut result = [0u8; G1_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        result[..32].copy_from_slice(&encode_fq(*x));
+        result[32..].copy_from_slice(&encode_fq(*y));
+    }
+    result
+}
+
+/// Decodes a G2 point, checking that it is on the curve and in the
+/// prime-order subgroup, as required by EIP-197.
+pub fn decode_g2(bytes: &[u8; G2_ENCODED_SIZE]) -> Result<G2Affine, SerializationError> {
+    let read = |i: usize| decode_fq(bytes[32 * i..32 * (i + 1)].try_into().unwrap());
+    let x = Fq2::new(read(1)?, read(0)?);
+    let y = Fq2::new(read(3)?, read(2)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+
+    let p = G2Affine::new_unchecked(x, y);
+    if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(p)
+}
+
+/// Encodes a G2 point as `x.c1 || x.c0 || y.c1 || y.c0`, and the point at
+/// infinity as all zeros.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_ENCODED_SIZE] {
+    let mut result = [0u8; G2_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        result[..32].copy_from_slice(&encode_fq(x.c1));
+        result[32..64].copy_from_slice(&encode_fq(x.c0));
+        result[64..96].copy_from_slice(&encode_fq(y.c1));
+        result[96..].copy_from_slice(&encode_fq(y.c0));
+    }
+    result
+}
+
+/// The `ecAdd` precompile at address 0x06.
+///
+/// The input is implicitly padded with zeros, or truncated, to 128 bytes.
+pub fn ec_add(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], SerializationError> {
+    let input = pad::<EC_ADD_INPUT_SIZE>(input);
+    let p = decode_g1(input[..64].try_into().unwrap())?;
+    let q = decode_g1(input[64..].try_into().unwrap())?;
+    Ok(encode_g1(&(p + q).into_affine()))
+}
+
+/// The `ecMul` precompile at address 0x07.
+///
+/// The input is implicitly padded with zeros, or truncated, to 96 bytes. The
+/// scalar is an arbitrary 256-bit big-endian integer.
+pub fn ec_mul(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], SerializationError> {
+    let input = pad::<EC_MUL_INPUT_SIZE>(input);
+    let p = decode_g1(input[..64].try_into().unwrap())?;
+    let scalar = read_u256(input[64..].try_into().unwrap());
+    Ok(encode_g1(&p.mul_bigint(scalar).into_affine()))
+}
+
+/// The `ecPairing` precompile at address 0x08.
+///
+/// The input is a sequence of pairs of a G1 and a G2 point, and its length
+/// must be a multiple of 192 bytes. The output is 1 as a 32-byte big-endian
+/// integer if the product of the pairings is 1, and 0 otherwise. In
+/// particular, the output is 1 for the empty input.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], SerializationError> {
+    let pairs = input.chunks_exact(EC_PAIRING_PAIR_SIZE);
+    if !pairs.remainder().is_empty() {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let mut g1 = Vec::with_capacity(pairs.len());
+    let mut g2 = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        g1.push(decode_g1(pair[..64].try_into().unwrap())?);
+        g2.push(decode_g2(pair[64..].try_into().unwrap())?);
+    }
+
+    let mut result = [0u8; 32];
+    if Bn254::multi_pairing(g1, g2).0.is_one() {
+        result[31] = 1;
+    }
+    Ok(result)
+}
+
+fn read_u256(bytes: &[u8; 32]) -> BigInteger256 {
+    let mut tmp = BigInteger256::zero();
+    for (limb, chunk) in tmp.0.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    tmp
+}
+
+/// Right-pads `input` with zeros, or truncates it, to `N` bytes.
+fn pad<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut result = [0u8; N];
+    let len = input.len().min(N);
+    result[..len].copy_from_slice(&input[..len]);
+    result
+}
@@ -1,9 +1,16 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+};
 use ark_ec::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
-    short_weierstrass::Affine,
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{Affine, Projective},
+    AffineRepr,
 };
-use ark_ff::{Field, MontFp, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 
+use super::SVDWConfig;
 use crate::{Fq, Fr};
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -36,6 +43,71 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_projective(*p, s),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    #[inline]
+    fn mul_affine(base: &G1Affine, scalar: &[u64]) -> Projective<Self> {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_affine(*base, s).into(),
+            None => double_and_add(&base.into_group(), scalar),
+        }
+    }
+
+    /// The halves of the GLV decomposition of the scalars have at most 127 bits.
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<Projective<Self>, usize> {
+        (bases.len() == scalars.len())
+            .then(|| glv::glv_msm(bases, scalars, 127))
+            .ok_or(bases.len().min(scalars.len()))
+    }
+}
+
+impl GLVConfig for Config {
+    /// ENDO_COEFFS = [BETA], where BETA is a non-trivial cube root of unity in Fq:
+    /// BETA = 2203960485148121921418603742825762020974279258880205651966
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "2203960485148121921418603742825762020974279258880205651966"
+    )];
+
+    /// LAMBDA is the cube root of unity in Fr such that phi(P) = [LAMBDA]P:
+    /// LAMBDA = 4407920970296243842393367215006156084916469457145843978461
+    const LAMBDA: Self::ScalarField =
+        MontFp!("4407920970296243842393367215006156084916469457145843978461");
+
+    /// SCALAR_DECOMP_COEFFS = [(a1, b1), (a2, b2)], a reduced basis of the
+    /// lattice {(a, b) : a + b * LAMBDA = 0 mod r}, with a1 * b2 - a2 * b1 = r
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("9931322734385697763")),
+        (false, BigInt!("147946756881789319000765030803803410728")),
+        (true, BigInt!("147946756881789319010696353538189108491")),
+        (true, BigInt!("9931322734385697763")),
+    ];
+
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+}
+
+impl SVDWConfig for Config {
+    /// ZETA = 1, as found by the procedure of appendix H.1 of RFC 9380.
+    const ZETA: Fq = Fq::ONE;
 }
 
 /// G1_GENERATOR_X = 1
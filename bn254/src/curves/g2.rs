@@ -1,9 +1,13 @@
+use ark_curve_utils::scalar_mul::{double_and_add, scalar_field_element};
 use ark_ec::{
+    bn::BnConfig,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
-    short_weierstrass::Affine,
+    short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup, Group,
 };
-use ark_ff::{Field, MontFp, Zero};
+use ark_ff::{Field, MontFp, PrimeField, Zero};
 
+use super::SVDWConfig;
 use crate::{Fq, Fq2, Fr};
 
 pub type G2Affine = Affine<Config>;
@@ -48,6 +52,59 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        // Algorithm from Section 4.3 of https://eprint.iacr.org/2022/348.
+        //
+        // Checks that psi(P) = [6 * X^2]P
+        let point = point.into_group();
+        let x_times_point = double_and_add(&point, crate::Config::X);
+        let x2_times_point = double_and_add(&x_times_point, crate::Config::X);
+        let x2_times_point_3 = x2_times_point.double() + x2_times_point;
+
+        let p_times_point = p_power_endomorphism(&point);
+
+        x2_times_point_3.double().eq(&p_times_point)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // Based on Section 6.1 of "Faster Hashing to G2" by Fuentes-Castañeda,
+        // Knapp and Rodríguez-Henríquez.
+        //
+        // [h(ψ)]P = [x]P + ψ([3x]P) + (ψ^2)([x]P) + (ψ^3)(P)
+        let p_projective = p.into_group();
+
+        // [x]P
+        let x_p = double_and_add(&p_projective, crate::Config::X);
+        // ψ([3x]P)
+        let psi_3x_p = p_power_endomorphism(&(x_p.double() + x_p));
+        // (ψ^2)([x]P)
+        let psi2_x_p = p_power_endomorphism(&p_power_endomorphism(&x_p));
+        // (ψ^3)(P)
+        let psi3_p =
+            p_power_endomorphism(&p_power_endomorphism(&p_power_endomorphism(&p_projective)));
+
+        (x_p + psi_3x_p + psi2_x_p + psi3_p).into_affine()
+    }
+
+    #[inline]
+    fn mul_projective(p: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
+        match scalar_field_element(scalar) {
+            Some(s) => gls_mul(p, s),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    #[inline]
+    fn mul_affine(p: &G2Affine, scalar: &[u64]) -> Projective<Self> {
+        Self::mul_projective(&p.into_group(), scalar)
+    }
+}
+
+impl SVDWConfig for Config {
+    /// ZETA = 1, as found by the procedure of appendix H.1 of RFC 9380.
+    const ZETA: Fq2 = Fq2::ONE;
 }
 
 pub const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
@@ -72,3 +129,112 @@ pub const G2_GENERATOR_Y_C0: Fq =
 /// 4082367875863433681332203403145435568316851327593401208105741076214120093531
 pub const G2_GENERATOR_Y_C1: Fq =
     MontFp!("4082367875863433681332203403145435568316851327593401208105741076214120093531");
+
+/// psi(x, y) = (x^p * TWIST_MUL_BY_Q_X, y^p * TWIST_MUL_BY_Q_Y) is the
+/// untwist-Frobenius-twist endomorphism, which acts on G2 as the
+/// multiplication by p = 6 * X^2 mod r.
+fn p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
+    // Raising the Jacobian coordinates to the p-th power is the same as
+    // applying the Frobenius map to the affine ones.
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.x *= crate::Config::TWIST_MUL_BY_Q_X;
+    res.y.frobenius_map_in_place(1);
+    res.y *= crate::Config::TWIST_MUL_BY_Q_Y;
+    res.z.frobenius_map_in_place(1);
+    res
+}
+
+/// A reduced basis of the lattice
+/// {(a, b, c, d) : a + b * LAMBDA + c * LAMBDA^2 + d * LAMBDA^3 = 0 mod r},
+/// where LAMBDA = 6 * X^2 is the eigenvalue of psi on G2. Its determinant is -r.
+const GLS_BASIS: [[i128; 4]; 4] = {
+    let x = crate::Config::X[0] as i128;
+    [
+        [2 * x + 1, 0, 2 * x, 1],
+        [2 * x, x + 1, -x, x],
+        [x + 1, x, x, -2 * x],
+        [2 * x + 1, -x, -x - 1, -x],
+    ]
+};
+
+/// The first row of the inverse of GLS_BASIS, scaled by 2^256 and rounded
+/// down. All of its entries are positive.
+#[rustfmt::skip]
+const GLS_BABAI_COEFFS: [[u64; 4]; 4] = [
+    [0x2dff291532e42728, 0x55b4ca7ba3e5577f, 0x9e80318ab0d92b95, 0x0],
+    [0x46f4bda995d51bb1, 0x08e5da66fc7184ae, 0x9e80318ab0d92b93, 0x0],
+    [0xd91d232ec7e0b3d7, 0x2, 0x0, 0x0],
+    [0xc170977dcef3cd3f, 0x55b4ca7ba3e5577d, 0x9e80318ab0d92b95, 0x0],
+];
+
+/// Multiplies `p` by `k` as [k0]P + [k1]psi(P) + [k2]psi^2(P) + [k3]psi^3(P),
+/// where k = k0 + k1 * LAMBDA + k2 * LAMBDA^2 + k3 * LAMBDA^3 mod r and the
+/// k_i have at most 66 bits.
+fn gls_mul(p: &Projective<Config>, k: Fr) -> Projective<Config> {
+    let digits = gls_decomposition(k);
+
+    let mut bases = [*p; 4];
+    for i in 1..4 {
+        bases[i] = p_power_endomorphism(&bases[i - 1]);
+    }
+    for (base, (is_negative, _)) in bases.iter_mut().zip(digits) {
+        if is_negative {
+            *base = -*base;
+        }
+    }
+
+    // table[i] is the sum of the bases[j] such that the j-th bit of i is set
+    let mut table = [Projective::<Config>::zero(); 16];
+    for i in 1..16 {
+        let j = (i as u32).trailing_zeros() as usize;
+        table[i] = table[i & (i - 1)] + bases[j];
+    }
+
+    let num_bits = digits
+        .iter()
+        .map(|(_, d)| 128 - d.leading_zeros())
+        .max()
+        .unwrap_or(0);
+    let mut res = Projective::<Config>::zero();
+    for bit in (0..num_bits).rev() {
+        res.double_in_place();
+        let index = digits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (j, (_, d))| acc | (((d >> bit) & 1) as usize) << j);
+        res += &table[index];
+    }
+    res
+}
+
+/// Returns the signs and absolute values of the k_i, computed by rounding
+/// (k, 0, 0, 0) to a nearby vector of the lattice spanned by GLS_BASIS.
+fn gls_decomposition(k: Fr) -> [(bool, u128); 4] {
+    let k = k.into_bigint().0;
+
+    // The k_i are small, so it is enough to compute them modulo 2^128.
+    let mut res = [(k[0] as u128 | (k[1] as u128) << 64) as i128, 0, 0, 0];
+    for (g, b) in GLS_BABAI_COEFFS.iter().zip(GLS_BASIS) {
+        let c = mul_shift_256(&k, g) as i128;
+        for (r, b) in res.iter_mut().zip(b) {
+            *r = r.wrapping_sub(c.wrapping_mul(b));
+        }
+    }
+    res.map(|d| (d < 0, d.unsigned_abs()))
+}
+
+/// Returns the lowest 128 bits of (a * b) >> 256.
+fn mul_shift_256(a: &[u64; 4], b: &[u64; 4]) -> u128 {
+    let mut prod = [0u64; 8];
+    for (i, a) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b) in b.iter().enumerate() {
+            let t = (*a as u128) * (*b as u128) + prod[i + j] as u128 + carry;
+            prod[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        prod[i + 4] = carry as u64;
+    }
+    prod[4] as u128 | (prod[5] as u128) << 64
+}
@@ -6,9 +6,12 @@ use ark_ff::MontFp;
 
 use crate::*;
 
+pub mod eip196;
 pub mod g1;
 pub mod g2;
 
+pub use ark_curve_utils::svdw::{SVDWConfig, SVDWMap};
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,212 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_glv, test_h2c_xmd};
+use ark_ec::{
+    bn::BnConfig,
+    hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+    AffineRepr, CurveConfig, CurveGroup,
+};
+use ark_ff::{fields::Field, BigInteger, PrimeField, UniformRand, Zero};
+use ark_std::{format, fs::File, io::BufReader, rand::Rng, test_rng};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::{
+    eip196, g1::Config as G1Config, g2::Config as G2Config, Bn254, Fq, Fq2, Fr, G1Affine,
+    G1Projective, G2Affine, G2Projective, SVDWMap,
+};
+
+test_group!(g1; G1Projective; sw);
+test_group!(g2; G2Projective; sw);
+test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bn254>; msm);
+test_pairing!(pairing; crate::Bn254);
+test_glv!(g1_glv; G1Config; glv);
+test_glv!(g2_gls; G2Config; gls);
+
+#[test]
+fn test_g2_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G2Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_cofactor_clearing() {
+    // On the points of order dividing the cofactor h, [x] + psi o [3x] +
+    // psi^2 o [x] + psi^3 vanishes, while on G2 psi is the multiplication by
+    // q. Therefore the cofactor clearing is the multiplication by the
+    // effective cofactor h * k, where k = (x + 3xq + xq^2 + q^3) / h mod r.
+    let x_fr = Fr::from(<crate::Config as BnConfig>::X[0]);
+    let q_fr = Fr::from_le_bytes_mod_order(&Fq::MODULUS.to_bytes_le());
+    let h_fr = Fr::from_sign_and_limbs(true, G2Config::COFACTOR);
+    let k = x_fr * (Fr::ONE + q_fr * Fr::from(3u64) + q_fr.square()) + q_fr.square() * q_fr;
+    let k = k / h_fr;
+
+    let mut rng = test_rng();
+    let mut samples = 0;
+    while samples < 10 {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            let q = p.clear_cofactor();
+            assert!(!q.is_zero());
+            assert!(q.is_on_curve());
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+            assert!(q.mul_bigint(Fr::characteristic()).is_zero());
+            assert_eq!(q, p.mul_bigint(G2Config::COFACTOR) * k);
+            samples += 1;
+        }
+    }
+}
+
+fn read_vectors(path: &str) -> Value {
+    let file = File::open(path).unwrap();
+    serde_json::from_reader(BufReader::new(file)).unwrap()
+}
+
+// The vectors follow the format of the ones of RFC 9380, which has no suite
+// for BN254. For the empty message, P on G1 is also checked against the value
+// published by gnark-crypto below.
+test_h2c_xmd!(
+    g1_h2c;
+    "./src/curves/tests/BN254G1_XMD-SHA-256_SVDW_RO_.json";
+    G1Projective;
+    XMDFieldHasher<Sha256>;
+    SVDWMap<G1Config>
+);
+test_h2c_xmd!(
+    g2_h2c;
+    "./src/curves/tests/BN254G2_XMD-SHA-256_SVDW_RO_.json";
+    G2Projective;
+    XMDFieldHasher<Sha256>;
+    SVDWMap<G2Config>
+);
+
+// P of the first vector of `BN254G1_XMD:SHA-256_SVDW_RO_`, for the empty
+// message, in `ecc/bn254/hash_vectors_test.go` of gnark-crypto.
+#[test]
+fn test_g1_hash_to_curve_gnark_crypto() {
+    let hasher =
+        MapToCurveBasedHasher::<G1Projective, XMDFieldHasher<Sha256>, SVDWMap<G1Config>>::new(
+            b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_",
+        )
+        .unwrap();
+    let p = hasher.hash(b"").unwrap();
+    assert_eq!(
+        hex::encode(eip196::encode_g1(&p)),
+        "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86\
+         02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5"
+    );
+}
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    let g1_hasher =
+        MapToCurveBasedHasher::<G1Projective, XMDFieldHasher<Sha256>, SVDWMap<G1Config>>::new(
+            b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_",
+        )
+        .unwrap();
+    let p = g1_hasher.hash(b"abc").unwrap();
+    assert!(p.is_on_curve());
+
+    let g2_hasher =
+        MapToCurveBasedHasher::<G2Projective, XMDFieldHasher<Sha256>, SVDWMap<G2Config>>::new(
+            b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_",
+        )
+        .unwrap();
+    let p = g2_hasher.hash(b"abc").unwrap();
+    assert!(p.is_on_curve());
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+}
+
+/// Runs the precompile `f` on the vectors of `paths`, which follow the format
+/// of the go-ethereum precompile tests. The files named after the precompiles
+/// of go-ethereum, such as `eip196_bn256Add.json`, hold its vectors, as
+/// included in the tests of the `solana-bn254` crate.
+fn test_eip196_precompile<const N: usize>(
+    paths: &[&str],
+    f: fn(&[u8]) -> Result<[u8; N], ark_serialize::SerializationError>,
+) {
+    for path in paths {
+        let data = read_vectors(&format!("./src/curves/tests/eip196_{}.json", path));
+        for v in data.as_array().unwrap() {
+            let input = hex::decode(v["Input"].as_str().unwrap()).unwrap();
+            let name = v["Name"].as_str().unwrap();
+            match v.get("Expected") {
+                Some(expected) => assert_eq!(
+                    hex::encode(f(&input).unwrap()),
+                    expected.as_str().unwrap(),
+                    "{}",
+                    name
+                ),
+                None => assert!(f(&input).is_err(), "{}", name),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_eip196_ec_add() {
+    test_eip196_precompile(&["ec_add", "bn256Add"], eip196::ec_add);
+}
+
+#[test]
+fn test_eip196_ec_mul() {
+    test_eip196_precompile(&["ec_mul", "bn256ScalarMul"], eip196::ec_mul);
+}
+
+#[test]
+fn test_eip196_ec_mul_large_scalars() {
+    use ark_curve_utils::scalar_mul::double_and_add;
+    use ark_ff::BigInt;
+
+    let mut rng = test_rng();
+    let p = G1Projective::rand(&mut rng).into_affine();
+    let mut r_plus_5 = Fr::MODULUS;
+    r_plus_5.add_with_carry(&BigInt::from(5u64));
+    for scalar in [Fr::MODULUS, r_plus_5, BigInt([u64::MAX; 4])] {
+        let mut input = eip196::encode_g1(&p).to_vec();
+        input.extend_from_slice(&scalar.to_bytes_be());
+        let expected = double_and_add(&p.into_group(), scalar.as_ref()).into_affine();
+        assert_eq!(
+            eip196::ec_mul(&input).unwrap(),
+            eip196::encode_g1(&expected)
+        );
+    }
+}
+
+#[test]
+fn test_eip196_ec_pairing() {
+    test_eip196_precompile(&["ec_pairing", "bn256Pairing"], eip196::ec_pairing);
+}
+
+#[test]
+fn test_eip196_encoding_round_trip() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip196::decode_g1(&eip196::encode_g1(&p)).unwrap(), p);
+        let q = G2Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip196::decode_g2(&eip196::encode_g2(&q)).unwrap(), q);
+    }
+    assert_eq!(eip196::encode_g1(&G1Affine::zero()), [0u8; 64]);
+    assert_eq!(eip196::encode_g2(&G2Affine::zero()), [0u8; 128]);
+    assert!(eip196::decode_g1(&[0u8; 64]).unwrap().is_zero());
+    assert!(eip196::decode_g2(&[0u8; 128]).unwrap().is_zero());
+}
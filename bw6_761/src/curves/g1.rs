@@ -4,6 +4,7 @@ use ark_ec::{
 };
 use ark_ff::{Field, MontFp};
 
+use super::{util, SVDWConfig};
 use crate::{Fq, Fr};
 
 pub type G1Affine = Affine<Config>;
@@ -47,6 +48,23 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        util::is_in_correct_subgroup(p, endomorphism)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        // A(x) = -7x^3 + 7x^2 + 20x + 16
+        // B(x) = 13x^3 - 10x^2 + x + 26
+        util::clear_cofactor(p, endomorphism, [16, 20, 7, -7], [26, 1, -10, 13])
+    }
+}
+
+impl SVDWConfig for Config {
+    /// ZETA = -1, as found by the procedure of appendix H.1 of RFC 9380.
+    const ZETA: Fq = MontFp!("-1");
 }
 
 /// G1_GENERATOR_X =
@@ -56,3 +74,18 @@ pub const G1_GENERATOR_X: Fq = MontFp!("6238772257594679368032145693622812838779
 /// G1_GENERATOR_Y =
 /// 2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099
 pub const G1_GENERATOR_Y: Fq = MontFp!("2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099");
+
+/// BETA is a non-trivial cubic root of unity in Fq, such that the
+/// endomorphism acts on G1 as the multiplication by LAMBDA, where
+/// LAMBDA^2 + LAMBDA + 1 = 0 mod r and
+/// (x^3 - x^2 - 2x - 1) / 3 + (2x^3 - 2x^2 - x + 1) / 3 * LAMBDA = 0 mod r.
+pub const BETA: Fq = MontFp!("4922464560225523242118178942575080391082002530232324381063048548642823052024664478336818169867474395270858391911405337707247735739826664939444490469542109391530482826728203582549674992333383150446779312029624171857054392282775648");
+
+pub fn endomorphism(p: &G1Projective) -> G1Projective {
+    // Endomorphism of the points on the curve.
+    // endomorphism_p(x,y) = (BETA * x, y)
+    // where BETA is a non-trivial cubic root of unity in Fq.
+    let mut res = *p;
+    res.x *= BETA;
+    res
+}
@@ -4,6 +4,7 @@ use ark_ec::{
 };
 use ark_ff::{Field, MontFp};
 
+use super::{util, SVDWConfig};
 use crate::{Fq, Fr};
 
 pub type G2Affine = Affine<Config>;
@@ -48,6 +49,23 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        util::is_in_correct_subgroup(p, endomorphism)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // A(x) = -13x^3 + 10x^2 + 26x + 4
+        // B(x) = 7x^3 - 7x^2 + 13x + 23
+        util::clear_cofactor(p, endomorphism, [4, 26, 10, -13], [23, 13, -7, 7])
+    }
+}
+
+impl SVDWConfig for Config {
+    /// ZETA = 1, as found by the procedure of appendix H.1 of RFC 9380.
+    const ZETA: Fq = Fq::ONE;
 }
 
 /// G2_GENERATOR_X =
@@ -57,3 +75,18 @@ pub const G2_GENERATOR_X: Fq = MontFp!("6445332910596979336035888152774071626898
 /// G2_GENERATOR_Y =
 /// 562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041
 pub const G2_GENERATOR_Y: Fq = MontFp!("562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041");
+
+/// BETA is a non-trivial cubic root of unity in Fq, such that the
+/// endomorphism acts on G2 as the multiplication by LAMBDA, where
+/// LAMBDA^2 + LAMBDA + 1 = 0 mod r and
+/// (x^3 - x^2 - 2x - 1) / 3 + (2x^3 - 2x^2 - x + 1) / 3 * LAMBDA = 0 mod r.
+pub const BETA: Fq = MontFp!("1968985824090209297278610739700577151397666382303825728450741611566800370218827257750865013421937292370006175842381275743914023380727582819905021229583192207421122272650305267822868639090213645505120388400344940985710520836292650");
+
+pub fn endomorphism(p: &G2Projective) -> G2Projective {
+    // Endomorphism of the points on the curve.
+    // endomorphism_p(x,y) = (BETA * x, y)
+    // where BETA is a non-trivial cubic root of unity in Fq.
+    let mut res = *p;
+    res.x *= BETA;
+    res
+}
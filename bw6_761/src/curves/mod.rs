@@ -8,6 +8,9 @@ use crate::*;
 
 pub mod g1;
 pub mod g2;
+pub(crate) mod util;
+
+pub use ark_curve_utils::svdw::{SVDWConfig, SVDWMap};
 
 #[cfg(test)]
 mod tests;
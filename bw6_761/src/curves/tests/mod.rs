@@ -0,0 +1,148 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_h2c_xmd};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, MontFp, One, UniformRand, Zero};
+use ark_std::{rand::Rng, test_rng};
+use sha2::Sha256;
+
+test_group!(g1; G1Projective; sw);
+test_group!(g2; G2Projective; sw);
+test_group!(pairing_output; ark_ec::pairing::PairingOutput<BW6_761>; msm);
+test_pairing!(pairing; crate::BW6_761);
+
+#[test]
+fn test_endomorphism_beta() {
+    assert!(crate::g1::BETA.pow([3u64]).is_one());
+    assert!(crate::g2::BETA.pow([3u64]).is_one());
+}
+
+#[test]
+fn test_g1_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G1Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g1_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G2Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_non_membership_of_torsion_point() {
+    // (0, 2) has order 3.
+    let p = G2Affine::new_unchecked(Fq::zero(), MontFp!("2"));
+    assert!(p.is_on_curve());
+    assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g1_cofactor_clearing() {
+    let mut rng = test_rng();
+    let mut samples = 0;
+    while samples < 10 {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, greatest) {
+            let q = p.clear_cofactor();
+            assert!(!q.is_zero());
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+            assert!(q.mul_bigint(Fr::characteristic()).is_zero());
+            samples += 1;
+        }
+    }
+}
+
+#[test]
+fn test_g2_cofactor_clearing() {
+    let mut rng = test_rng();
+    let mut samples = 0;
+    while samples < 10 {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            let q = p.clear_cofactor();
+            assert!(!q.is_zero());
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+            assert!(q.mul_bigint(Fr::characteristic()).is_zero());
+            samples += 1;
+        }
+    }
+}
+
+test_h2c_xmd!(
+    g1_h2c;
+    "./src/curves/tests/BW6_761G1_XMD-SHA-256_SVDW_RO_.json";
+    G1Projective;
+    XMDFieldHasher<Sha256>;
+    SVDWMap<crate::g1::Config>
+);
+test_h2c_xmd!(
+    g2_h2c;
+    "./src/curves/tests/BW6_761G2_XMD-SHA-256_SVDW_RO_.json";
+    G2Projective;
+    XMDFieldHasher<Sha256>;
+    SVDWMap<crate::g2::Config>
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    use ark_ec::hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve};
+
+    let g1_hasher = MapToCurveBasedHasher::<
+        G1Projective,
+        XMDFieldHasher<Sha256>,
+        SVDWMap<crate::g1::Config>,
+    >::new(b"QUUX-V01-CS02-with-BW6_761G1_XMD:SHA-256_SVDW_RO_")
+    .unwrap();
+    let p = g1_hasher.hash(b"abc").unwrap();
+    assert!(p.is_on_curve());
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+
+    let g2_hasher = MapToCurveBasedHasher::<
+        G2Projective,
+        XMDFieldHasher<Sha256>,
+        SVDWMap<crate::g2::Config>,
+    >::new(b"QUUX-V01-CS02-with-BW6_761G2_XMD:SHA-256_SVDW_RO_")
+    .unwrap();
+    let p = g2_hasher.hash(b"abc").unwrap();
+    assert!(p.is_on_curve());
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+}
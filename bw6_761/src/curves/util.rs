@@ -0,0 +1,79 @@
+use ark_curve_utils::scalar_mul::double_and_add;
+use ark_ec::{
+    bw6::BW6Config,
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr, Group,
+};
+use ark_ff::{BitIteratorBE, Zero};
+
+use crate::Config;
+
+/// (x^3 - x^2 - 2x - 1) / 3 and (2x^3 - 2x^2 - x + 1) / 3, where x is the
+/// BW6-761 seed. If omega is a cube root of unity, then a + b * omega has norm
+/// a^2 - a * b + b^2 = r and divides the Frobenius endomorphism minus one, for
+/// both G1 and G2 with the matching choice of BETA.
+const SUBGROUP_TEST_A: [u64; 3] = [0xd3a7bfffffffffff, 0x2e16ba885fffffff, 0x0bf9b117dd04a400];
+const SUBGROUP_TEST_B: [u64; 3] = [0x2c58400000000000, 0x5c2d7510c0000000, 0x17f3622fba094800];
+
+/// Checks that [a]P + [b]phi(P) = 0, where (a, b) are `SUBGROUP_TEST_A` and
+/// `SUBGROUP_TEST_B` and phi is the endomorphism (x, y) -> (BETA * x, y).
+///
+/// The kernel of a + b * phi on E(Fq) is exactly the subgroup of order r, so
+/// unlike the checks based on a multiple of r this accepts no point of small
+/// order. [a]P + [b]phi(P) is computed by a joint double-and-add, which costs
+/// half as many doublings as the multiplication by r.
+pub(crate) fn is_in_correct_subgroup<P: SWCurveConfig>(
+    p: &Affine<P>,
+    endomorphism: fn(&Projective<P>) -> Projective<P>,
+) -> bool {
+    let p = p.into_group();
+    let phi_p = endomorphism(&p);
+    let table = [Projective::<P>::zero(), p, phi_p, p + phi_p];
+
+    let mut res = Projective::<P>::zero();
+    let bits_a = BitIteratorBE::new(SUBGROUP_TEST_A);
+    let bits_b = BitIteratorBE::new(SUBGROUP_TEST_B);
+    for (a, b) in bits_a.zip(bits_b) {
+        res.double_in_place();
+        res += &table[a as usize | (b as usize) << 1];
+    }
+    res.is_zero()
+}
+
+/// Computes [A(x)]P + phi([B(x)]P), where A and B are polynomials of degree 3
+/// in the BW6-761 seed x with small coefficients, given from the constant
+/// term up.
+///
+/// As in https://eprint.iacr.org/2020/351, this replaces the multiplication by
+/// the cofactor: A + B * omega is three times a divisor of norm h of the
+/// Frobenius endomorphism minus one, so A + B * phi kills the points of E(Fq)
+/// of order coprime to r and acts on the subgroup of order r as the
+/// multiplication by a non-zero scalar.
+pub(crate) fn clear_cofactor<P: SWCurveConfig>(
+    p: &Affine<P>,
+    endomorphism: fn(&Projective<P>) -> Projective<P>,
+    a: [i64; 4],
+    b: [i64; 4],
+) -> Affine<P> {
+    let x: &[u64] = Config::X.as_ref();
+    let mut x_powers = [p.into_group(); 4];
+    for i in 1..4 {
+        x_powers[i] = double_and_add(&x_powers[i - 1], x);
+    }
+
+    let poly = |coeffs: [i64; 4]| {
+        x_powers
+            .iter()
+            .zip(coeffs)
+            .fold(Projective::<P>::zero(), |acc, (x_i_p, c)| {
+                let term = double_and_add(x_i_p, &[c.unsigned_abs()]);
+                if c < 0 {
+                    acc - term
+                } else {
+                    acc + term
+                }
+            })
+    };
+
+    (poly(a) + endomorphism(&poly(b))).into()
+}
@@ -0,0 +1,125 @@
+//! The Elligator 2 map of section 6.7.1 of RFC 9380, for twisted Edwards
+//! curves.
+//!
+//! The map is defined on a Montgomery curve K * t^2 = s^3 + J * s^2 + s,
+//! which is by default the Montgomery form of the twisted Edwards curve. As in
+//! the RFC, it is computed on the curve
+//! y^2 = x^3 + (J / K) * x^2 + x / K^2, where (s, t) = (x * K, y * K), so that
+//! the sign of the output is the sign of y rather than of t.
+//!
+//! The point (s, t) is then mapped to the twisted Edwards curve
+//! a * x^2 + y^2 = 1 + d * x^2 * y^2 by the rational map of appendix D.1 of
+//! RFC 9380, (s, t) -> (c * s / t, (s - 1) / (s + 1)), which sends the
+//! exceptional points t = 0 and s = -1 to the identity. With c = 1, this is
+//! the birational equivalence for which a = (J + 2) / K and d = (J - 2) / K.
+//! Some suites instead map to a curve whose Montgomery form is scaled, such
+//! as `edwards25519_XMD:SHA-512_ELL2_RO_`, which maps to curve25519, where
+//! K = 1, and then to edwards25519, where a = -1, with c = sqrt(-486664). In
+//! general, a = (J + 2) / (K * c^2) and d = (J - 2) / (K * c^2).
+//!
+//! Z must be a non-square of the base field. RFC 9380 chooses the non-square
+//! of smallest absolute value, the positive one in case of a tie, which is 2
+//! for the base field of curve25519, and 5 for the scalar field of BLS12-381.
+
+use ark_ec::{
+    hashing::{curve_maps::swu::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+};
+use ark_ff::{Field, One, Zero};
+use ark_std::string::ToString;
+
+/// Trait defining the parameters of the Elligator 2 map of a twisted Edwards
+/// curve.
+pub trait Elligator2Config: TECurveConfig {
+    /// A non-square Z of the base field.
+    const Z: Self::BaseField;
+
+    /// The coefficient J of the Montgomery curve K * t^2 = s^3 + J * s^2 + s.
+    const COEFF_J: Self::BaseField = <Self::MontCurveConfig as MontCurveConfig>::COEFF_A;
+
+    /// The coefficient K of the Montgomery curve K * t^2 = s^3 + J * s^2 + s.
+    const COEFF_K: Self::BaseField = <Self::MontCurveConfig as MontCurveConfig>::COEFF_B;
+
+    /// The factor c of the map (s, t) -> (c * s / t, (s - 1) / (s + 1)) from
+    /// the Montgomery form to the twisted Edwards form.
+    const EDWARDS_SCALE: Self::BaseField = Self::BaseField::ONE;
+}
+
+/// Represents the Elligator 2 map defined by `P`, together with the
+/// constants J / K and 1 / K^2.
+pub struct Elligator2Map<P: Elligator2Config> {
+    j_over_k: P::BaseField,
+    one_over_k_square: P::BaseField,
+}
+
+impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
+    /// Checks that `P::Z` is a non-square, and that `P::EDWARDS_SCALE` maps the
+    /// Montgomery form onto the twisted Edwards form.
+    fn new() -> Result<Self, HashToCurveError> {
+        if P::Z.legendre().is_qr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "Z should be a quadratic non-residue for the Elligator 2 map".to_string(),
+            ));
+        }
+
+        let (j, k) = (P::COEFF_J, P::COEFF_K);
+        let k_c_square = k * P::EDWARDS_SCALE.square();
+        let two = P::BaseField::from(2u8);
+        if k_c_square.is_zero()
+            || P::COEFF_A * k_c_square != j + two
+            || P::COEFF_D * k_c_square != j - two
+        {
+            return Err(HashToCurveError::MapToCurveError(
+                "EDWARDS_SCALE should map the Montgomery form onto the twisted Edwards form"
+                    .to_string(),
+            ));
+        }
+
+        let k_inv = k.inverse().unwrap();
+        Ok(Elligator2Map {
+            j_over_k: j * k_inv,
+            one_over_k_square: k_inv.square(),
+        })
+    }
+
+    /// Map an arbitrary base field element to a curve point.
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let (s, t) = self.map_to_montgomery(point);
+
+        let s_plus_one = s + P::BaseField::one();
+        let den = t * s_plus_one;
+        if den.is_zero() {
+            return Ok(Affine::zero());
+        }
+        let den_inv = den.inverse().unwrap();
+        let x = P::EDWARDS_SCALE * s * s_plus_one * den_inv;
+        let y = (s - P::BaseField::one()) * t * den_inv;
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
+
+impl<P: Elligator2Config> Elligator2Map<P> {
+    /// Elligator 2 onto the Montgomery curve of `P`, computed on the curve
+    /// y^2 = x^3 + (J / K) * x^2 + x / K^2, where (s, t) = (x * K, y * K).
+    fn map_to_montgomery(&self, u: P::BaseField) -> (P::BaseField, P::BaseField) {
+        let g = |x: P::BaseField| x * ((x + self.j_over_k) * x + self.one_over_k_square);
+
+        let den = P::BaseField::one() + P::Z * u.square();
+        let x1 = -self.j_over_k * den.inverse().unwrap_or(P::BaseField::one());
+        let gx1 = g(x1);
+        let (x, gx, sign) = if gx1.legendre().is_qr() {
+            (x1, gx1, true)
+        } else {
+            let x2 = -x1 - self.j_over_k;
+            (x2, g(x2), false)
+        };
+
+        let mut y = gx
+            .sqrt()
+            .expect("either g(x1) or g(x2) is a quadratic residue");
+        if parity(&y) != sign {
+            y = -y;
+        }
+        (x * P::COEFF_K, y * P::COEFF_K)
+    }
+}
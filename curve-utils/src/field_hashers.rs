@@ -0,0 +1,103 @@
+use ark_ff::{field_hashers::HashToField, Field, PrimeField};
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+use digest::{core_api::BlockSizeUser, Digest};
+
+const MAX_DST_LENGTH: usize = 255;
+
+const LONG_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// The `hash_to_field` function of section 5.2 of RFC 9380, using the
+/// `expand_message_xmd` of section 5.3.1 instantiated with `H`, for a target
+/// security level of `SEC_PARAM` bits.
+///
+/// `ark_ff::field_hashers::DefaultFieldHasher` pads the input of its
+/// expand_message_xmd with `len_per_base_elem` zero bytes, that is L bytes,
+/// rather than with the input block of `H`. The two only coincide when L is
+/// the block size of `H`, such as L = 64 with SHA-256, so that it computes
+/// the suites of RFC 9380 for BLS12-381 but not, for instance, for P-256
+/// (L = 48) or for the Pasta curves with BLAKE2b (a 128-byte block). This is
+/// the `hash_to_field` of the RFC 9380 suites for every field size.
+pub struct XMDFieldHasher<H: Digest + BlockSizeUser, const SEC_PARAM: usize = 128> {
+    dst_prime: Vec<u8>,
+    len_per_base_elem: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Digest + BlockSizeUser, const SEC_PARAM: usize> XMDFieldHasher<H, SEC_PARAM> {
+    /// The `expand_message_xmd` function of section 5.3.1 of RFC 9380.
+    pub fn expand_message(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        let b_in_bytes = <H as Digest>::output_size();
+        let ell = len_in_bytes.div_ceil(b_in_bytes);
+        assert!(ell <= 255 && len_in_bytes <= 65535);
+
+        let b_0 = H::new()
+            .chain_update(vec![0u8; H::block_size()])
+            .chain_update(msg)
+            .chain_update((len_in_bytes as u16).to_be_bytes())
+            .chain_update([0u8])
+            .chain_update(&self.dst_prime)
+            .finalize();
+
+        let mut b_i = H::new()
+            .chain_update(&b_0)
+            .chain_update([1u8])
+            .chain_update(&self.dst_prime)
+            .finalize();
+        let mut uniform_bytes = b_i.to_vec();
+        for i in 2..=ell {
+            let xored: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+            b_i = H::new()
+                .chain_update(xored)
+                .chain_update([i as u8])
+                .chain_update(&self.dst_prime)
+                .finalize();
+            uniform_bytes.extend_from_slice(&b_i);
+        }
+        uniform_bytes.truncate(len_in_bytes);
+        uniform_bytes
+    }
+}
+
+impl<F: Field, H: Digest + BlockSizeUser, const SEC_PARAM: usize> HashToField<F>
+    for XMDFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        // Section 5.3.3: DSTs longer than 255 bytes are hashed first.
+        let mut dst_prime = if dst.len() > MAX_DST_LENGTH {
+            H::new()
+                .chain_update(LONG_DST_PREFIX)
+                .chain_update(dst)
+                .finalize()
+                .to_vec()
+        } else {
+            dst.to_vec()
+        };
+        dst_prime.push(dst_prime.len() as u8);
+
+        // L = ceil((ceil(log2(p)) + k) / 8)
+        let len_per_base_elem =
+            (F::BasePrimeField::MODULUS_BIT_SIZE as usize + SEC_PARAM).div_ceil(8);
+
+        XMDFieldHasher {
+            dst_prime,
+            len_per_base_elem,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn hash_to_field(&self, msg: &[u8], count: usize) -> Vec<F> {
+        let m = F::extension_degree() as usize;
+        let uniform_bytes = self.expand_message(msg, count * m * self.len_per_base_elem);
+
+        uniform_bytes
+            .chunks(m * self.len_per_base_elem)
+            .map(|elem_bytes| {
+                let base_elems: Vec<_> = elem_bytes
+                    .chunks(self.len_per_base_elem)
+                    .map(F::BasePrimeField::from_be_bytes_mod_order)
+                    .collect();
+                F::from_base_prime_field_elems(&base_elems).unwrap()
+            })
+            .collect()
+    }
+}
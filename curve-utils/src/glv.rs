@@ -0,0 +1,168 @@
+use ark_ec::{
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    Group,
+};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::{cfg_into_iter, vec, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Computes the multi-scalar multiplication of `bases` and `scalars` by
+/// decomposing every scalar k as k1 + LAMBDA * k2. This yields a multi-scalar
+/// multiplication over twice as many bases, namely the bases and their images
+/// under the endomorphism, but with scalars of at most `half_bits` bits, which
+/// halves the number of windows of Pippenger's algorithm.
+///
+/// `half_bits` must bound the absolute values of k1 and k2 given by
+/// [`GLVConfig::scalar_decomposition`]. Like [`GLVConfig::glv_mul_affine`],
+/// this is only valid for points of the prime-order subgroup.
+pub fn glv_msm<P: GLVConfig>(
+    bases: &[Affine<P>],
+    scalars: &[P::ScalarField],
+    half_bits: usize,
+) -> Projective<P> {
+    let size = bases.len().min(scalars.len());
+    let mut glv_bases = Vec::with_capacity(2 * size);
+    let mut glv_bigints = Vec::with_capacity(2 * size);
+    for (base, scalar) in bases.iter().zip(scalars) {
+        let ((sgn_k1, k1), (sgn_k2, k2)) = P::scalar_decomposition(*scalar);
+        let endo_base = P::endomorphism_affine(base);
+        glv_bases.push(if sgn_k1 { *base } else { -*base });
+        glv_bases.push(if sgn_k2 { endo_base } else { -endo_base });
+        glv_bigints.push(k1.into_bigint());
+        glv_bigints.push(k2.into_bigint());
+    }
+    debug_assert!(glv_bigints
+        .iter()
+        .all(|k| k.num_bits() as usize <= half_bits));
+
+    msm_bigint(&glv_bases, &glv_bigints, half_bits)
+}
+
+/// Pippenger's bucket method, for scalars smaller than 2^`num_bits`.
+///
+/// This is the algorithm of `VariableBaseMSM::msm_bigint`, which always
+/// processes `MODULUS_BIT_SIZE` bits of every scalar. As there, the windows
+/// are processed in parallel with the `parallel` feature.
+pub fn msm_bigint<P: SWCurveConfig>(
+    bases: &[Affine<P>],
+    bigints: &[<P::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
+) -> Projective<P> {
+    let size = bases.len().min(bigints.len());
+    let c = if size < 32 {
+        3
+    } else {
+        // ln(size) + 2, computed as in `ark_ec::scalar_mul::ln_without_floats`.
+        ark_std::log2(size) as usize * 69 / 100 + 2
+    };
+
+    let zero = Projective::<P>::zero();
+    let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+    let window_sums: Vec<_> = cfg_into_iter!(window_starts)
+        .map(|w_start| {
+            // The bucket i holds the sum of the bases whose current digit is
+            // i + 1; the bases whose digit is zero are skipped.
+            let mut buckets = vec![zero; (1 << c) - 1];
+            for (base, bigint) in bases.iter().zip(bigints) {
+                let digit = window_digit(bigint.as_ref(), w_start, c);
+                if digit != 0 {
+                    buckets[digit - 1] += base;
+                }
+            }
+
+            // The sum of (i + 1) * buckets[i], as a sum of running sums.
+            let mut running_sum = zero;
+            let mut res = zero;
+            for bucket in buckets.into_iter().rev() {
+                running_sum += &bucket;
+                res += &running_sum;
+            }
+            res
+        })
+        .collect();
+
+    // Horner's rule over the windows, starting from the most significant.
+    window_sums.iter().rev().fold(zero, |mut total, sum| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum
+    })
+}
+
+/// Returns the bits `start..start + c` of the little-endian `limbs`.
+fn window_digit(limbs: &[u64], start: usize, c: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut digit = limbs.get(limb).map_or(0, |l| l >> shift);
+    if shift + c > 64 {
+        digit |= limbs.get(limb + 1).map_or(0, |l| l << (64 - shift));
+    }
+    (digit & ((1 << c) - 1)) as usize
+}
+
+/// Computes [k]P using the 4-dimensional GLS method, for an endomorphism
+/// `psi` that acts on the prime-order subgroup as multiplication by x, where
+/// `x_abs` = |x| < 2^64, `x_is_negative` is the sign of x, and r < x^4. This
+/// is the case of the p-power endomorphism on the G2 of a BLS12 curve.
+///
+/// Writing k in base |x| as k = k_0 + k_1 |x| + k_2 |x|^2 + k_3 |x|^3, where
+/// each k_i is smaller than |x|, gives [k]P = sum_i [k_i](±psi)^i(P), as ±psi
+/// acts as multiplication by |x|. Like [`GLVConfig::glv_mul_projective`],
+/// this is only valid for points of the prime-order subgroup.
+pub fn gls_mul<P: SWCurveConfig>(
+    p: &Projective<P>,
+    k: P::ScalarField,
+    x_abs: u64,
+    x_is_negative: bool,
+    psi: impl Fn(&Projective<P>) -> Projective<P>,
+) -> Projective<P> {
+    let digits = x_adic_digits(k, x_abs);
+
+    // bases[i] = (±psi)^i(P)
+    let mut bases = [*p; 4];
+    for i in 1..4 {
+        let psi_base = psi(&bases[i - 1]);
+        bases[i] = if x_is_negative { -psi_base } else { psi_base };
+    }
+
+    // table[i] is the sum of the bases[j] such that the j-th bit of i is set
+    let mut table = [Projective::<P>::zero(); 16];
+    for i in 1..16 {
+        let j = (i as u32).trailing_zeros() as usize;
+        table[i] = table[i & (i - 1)] + bases[j];
+    }
+
+    let mut res = Projective::<P>::zero();
+    for bit in (0..64).rev() {
+        res.double_in_place();
+        let index = digits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (j, d)| acc | (((d >> bit) & 1) as usize) << j);
+        res += &table[index];
+    }
+    res
+}
+
+/// Returns the four digits of the base-`x` expansion of `k`, which must be
+/// smaller than x^4.
+fn x_adic_digits<F: PrimeField>(k: F, x: u64) -> [u64; 4] {
+    let x = x as u128;
+    let mut bigint = k.into_bigint();
+    let limbs = bigint.as_mut();
+    let mut digits = [0u64; 4];
+    for digit in digits.iter_mut() {
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 64) | (*limb as u128);
+            *limb = (cur / x) as u64;
+            rem = cur % x;
+        }
+        *digit = rem as u64;
+    }
+    debug_assert!(limbs.iter().all(|limb| *limb == 0));
+    digits
+}
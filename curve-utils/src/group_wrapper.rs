@@ -0,0 +1,176 @@
+/// Implements the traits of a prime-order group `$name`, a newtype over a
+/// projective point `$inner` of scalar field `$scalar`, whose representatives
+/// are points of `$inner`.
+///
+/// The group law, the scalar multiplications, `Group`, `Default`, `Zeroize`
+/// and sampling are forwarded to `$inner`. `Hash`, `Display`, `Debug` and
+/// `CanonicalSerialize` use the canonical encoding `$name::encode`, which
+/// must return `[u8; $encoded_size]`. `PartialEq`, `Zero`, `Valid` and
+/// `CanonicalDeserialize` depend on which points represent the same element,
+/// and are left to the caller.
+///
+/// The calling crate needs `zeroize` as a dependency.
+#[macro_export]
+macro_rules! impl_group_wrapper {
+    ($name:ident; $inner:ty; $scalar:ty; $encoded_size:expr) => {
+        impl ark_ec::Group for $name {
+            type ScalarField = $scalar;
+
+            /// The generator is represented by the generator of the curve.
+            fn generator() -> Self {
+                Self(<$inner as ark_ec::Group>::generator())
+            }
+
+            fn double_in_place(&mut self) -> &mut Self {
+                ark_ec::Group::double_in_place(&mut self.0);
+                self
+            }
+
+            fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+                Self(ark_ec::Group::mul_bigint(&self.0, other))
+            }
+        }
+
+        impl ark_std::hash::Hash for $name {
+            fn hash<H: ark_std::hash::Hasher>(&self, state: &mut H) {
+                ark_std::hash::Hash::hash(&self.encode(), state)
+            }
+        }
+
+        impl ark_std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+                for byte in self.encode() {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl ark_std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                <Self as ark_ff::Zero>::zero()
+            }
+        }
+
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+
+        impl ark_std::rand::distributions::Distribution<$name>
+            for ark_std::rand::distributions::Standard
+        {
+            fn sample<R: ark_std::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name(rng.gen())
+            }
+        }
+
+        impl ark_std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        $crate::__impl_group_wrapper_op!($name; Add, add, AddAssign, add_assign);
+        $crate::__impl_group_wrapper_op!($name; Sub, sub, SubAssign, sub_assign);
+
+        impl ark_std::ops::Mul<$scalar> for $name {
+            type Output = Self;
+
+            fn mul(self, other: $scalar) -> Self {
+                Self(self.0 * other)
+            }
+        }
+
+        impl<'a> ark_std::ops::Mul<&'a $scalar> for $name {
+            type Output = Self;
+
+            fn mul(self, other: &'a $scalar) -> Self {
+                Self(self.0 * other)
+            }
+        }
+
+        impl ark_std::ops::MulAssign<$scalar> for $name {
+            fn mul_assign(&mut self, other: $scalar) {
+                self.0 *= other;
+            }
+        }
+
+        impl<'a> ark_std::ops::MulAssign<&'a $scalar> for $name {
+            fn mul_assign(&mut self, other: &'a $scalar) {
+                self.0 *= *other;
+            }
+        }
+
+        impl ark_std::iter::Sum<Self> for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self(iter.map(|p| p.0).sum())
+            }
+        }
+
+        impl<'a> ark_std::iter::Sum<&'a Self> for $name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self(iter.map(|p| p.0).sum())
+            }
+        }
+
+        /// Elements are serialized with their canonical encoding, regardless
+        /// of compression.
+        impl ark_serialize::CanonicalSerialize for $name {
+            fn serialize_with_mode<W: ark_std::io::Write>(
+                &self,
+                mut writer: W,
+                _compress: ark_serialize::Compress,
+            ) -> Result<(), ark_serialize::SerializationError> {
+                Ok(writer.write_all(&self.encode())?)
+            }
+
+            fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+                $encoded_size
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_group_wrapper_op {
+    ($name:ident; $tr:ident, $f:ident, $tr_assign:ident, $f_assign:ident) => {
+        impl ark_std::ops::$tr<Self> for $name {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self {
+                Self(ark_std::ops::$tr::$f(self.0, other.0))
+            }
+        }
+
+        impl<'a> ark_std::ops::$tr<&'a Self> for $name {
+            type Output = Self;
+
+            fn $f(self, other: &'a Self) -> Self {
+                Self(ark_std::ops::$tr::$f(self.0, other.0))
+            }
+        }
+
+        impl ark_std::ops::$tr_assign<Self> for $name {
+            fn $f_assign(&mut self, other: Self) {
+                ark_std::ops::$tr_assign::$f_assign(&mut self.0, other.0);
+            }
+        }
+
+        impl<'a> ark_std::ops::$tr_assign<&'a Self> for $name {
+            fn $f_assign(&mut self, other: &'a Self) {
+                ark_std::ops::$tr_assign::$f_assign(&mut self.0, other.0);
+            }
+        }
+    };
+}
@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(
+    warnings,
+    unused,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms
+)]
+#![forbid(unsafe_code)]
+
+//! This library implements the algorithms that are shared by several curves
+//! of this repository, so that each curve only provides its parameters.
+
+pub mod elligator2;
+pub mod field_hashers;
+pub mod glv;
+pub mod scalar_mul;
+pub mod sec1;
+pub mod svdw;
+pub mod zcash;
+
+mod group_wrapper;
+mod test_templates;
@@ -0,0 +1,34 @@
+use ark_ec::{
+    short_weierstrass::{Projective, SWCurveConfig},
+    Group,
+};
+use ark_ff::{BigInteger, BitIteratorBE, PrimeField, Zero};
+
+/// Returns `scalar` as an element of `F` if it is smaller than the modulus.
+///
+/// Only such scalars are multiplied using the endomorphism-based methods,
+/// which are valid for points of the prime-order subgroups. Larger scalars,
+/// such as multiples of the group order, are handled by [`double_and_add`].
+pub fn scalar_field_element<F: PrimeField>(scalar: &[u64]) -> Option<F> {
+    let mut repr = F::BigInt::default();
+    let (low, high) = scalar.split_at(scalar.len().min(F::BigInt::NUM_LIMBS));
+    if high.iter().any(|limb| *limb != 0) {
+        return None;
+    }
+    repr.as_mut()[..low.len()].copy_from_slice(low);
+    F::from_bigint(repr)
+}
+
+/// Plain double-and-add scalar multiplication. Unlike the endomorphism-based
+/// methods, it is valid for every point of the curve, including the ones
+/// outside of the prime-order subgroup.
+pub fn double_and_add<P: SWCurveConfig>(p: &Projective<P>, scalar: &[u64]) -> Projective<P> {
+    let mut res = Projective::<P>::zero();
+    for b in BitIteratorBE::without_leading_zeros(scalar) {
+        res.double_in_place();
+        if b {
+            res += p;
+        }
+    }
+    res
+}
@@ -0,0 +1,94 @@
+//! The point and scalar encodings of [SEC 1], for a short Weierstrass curve
+//! whose field elements and scalars are encoded with `N` bytes.
+//!
+//! Field elements and scalars are encoded as `N`-byte big-endian integers,
+//! which must be smaller than the modulus. A point is encoded as
+//! `0x02 || x` or `0x03 || x` when compressed, depending on whether y is even
+//! or odd, and as `0x04 || x || y` when uncompressed. The point at infinity
+//! is encoded as the single byte `0x00`.
+//!
+//! [SEC 1]: https://www.secg.org/sec1-v2.pdf
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{Compress, SerializationError};
+use ark_std::{vec, vec::Vec};
+
+/// Encodes `p`, which is the single byte `0x00` for the point at infinity.
+pub fn encode_point<P: SWCurveConfig, const N: usize>(p: &Affine<P>, compress: Compress) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let (x, y) = match p.xy() {
+        Some(xy) => xy,
+        None => return vec![0x00],
+    };
+
+    let mut result = Vec::with_capacity(1 + 2 * N);
+    match compress {
+        Compress::Yes => result.push(if y.into_bigint().is_odd() { 0x03 } else { 0x02 }),
+        Compress::No => result.push(0x04),
+    }
+    result.extend_from_slice(&encode_field_element::<_, N>(*x));
+    if compress == Compress::No {
+        result.extend_from_slice(&encode_field_element::<_, N>(*y));
+    }
+    result
+}
+
+/// Decodes a compressed, uncompressed or infinity point encoding. The
+/// coordinates must be smaller than p and the point must be on the curve.
+/// Hybrid encodings are rejected.
+///
+/// The point is not checked to be in the prime-order subgroup, which is the
+/// whole curve when the cofactor is 1.
+pub fn decode_point<P: SWCurveConfig, const N: usize>(
+    bytes: &[u8],
+) -> Result<Affine<P>, SerializationError>
+where
+    P::BaseField: PrimeField,
+{
+    match bytes.first() {
+        Some(0x00) if bytes.len() == 1 => Ok(Affine::zero()),
+        Some(tag @ (0x02 | 0x03)) if bytes.len() == 1 + N => {
+            let x = decode_field_element(&bytes[1..])?;
+            let p = Affine::<P>::get_point_from_x_unchecked(x, false)
+                .ok_or(SerializationError::InvalidData)?;
+            if p.y.into_bigint().is_odd() == (*tag == 0x03) {
+                Ok(p)
+            } else {
+                Ok(-p)
+            }
+        },
+        Some(0x04) if bytes.len() == 1 + 2 * N => {
+            let (x, y) = bytes[1..].split_at(N);
+            let p = Affine::new_unchecked(decode_field_element(x)?, decode_field_element(y)?);
+            if !p.is_on_curve() {
+                return Err(SerializationError::InvalidData);
+            }
+            Ok(p)
+        },
+        _ => Err(SerializationError::InvalidData),
+    }
+}
+
+/// Encodes `s` as an `N`-byte big-endian integer.
+pub fn encode_field_element<F: PrimeField, const N: usize>(s: F) -> [u8; N] {
+    let bytes = s.into_bigint().to_bytes_be();
+    let (padding, bytes) = bytes.split_at(bytes.len() - N);
+    debug_assert!(padding.iter().all(|b| *b == 0));
+    bytes.try_into().unwrap()
+}
+
+/// Decodes a big-endian integer, which must be smaller than the modulus of
+/// `F`.
+pub fn decode_field_element<F: PrimeField>(bytes: &[u8]) -> Result<F, SerializationError> {
+    let s = F::from_be_bytes_mod_order(bytes);
+    if !s.into_bigint().to_bytes_be().ends_with(bytes) {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(s)
+}
@@ -0,0 +1,96 @@
+use ark_ec::{
+    hashing::{curve_maps::swu::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+};
+use ark_ff::{Field, One, Zero};
+use ark_std::string::ToString;
+
+/// Trait defining the parameter of the Shallue–van de Woestijne map, as
+/// specified in section 6.6.1 of RFC 9380. Unlike the simplified SWU map, it
+/// applies to every short Weierstrass curve, in particular to the ones with
+/// `COEFF_A = 0`.
+pub trait SVDWConfig: SWCurveConfig {
+    /// An element Z of the base field such that g(Z) != 0,
+    /// -(3 * Z^2 + 4 * A) / (4 * g(Z)) is a non-zero square, and g(Z) or
+    /// g(-Z / 2) is a square, where g(x) = x^3 + A * x + B.
+    const ZETA: Self::BaseField;
+}
+
+/// Represents the Shallue–van de Woestijne map defined by `P`, together with
+/// the constants c1, ..., c4 of RFC 9380 derived from `P::ZETA`.
+pub struct SVDWMap<P: SVDWConfig> {
+    c1: P::BaseField,
+    c2: P::BaseField,
+    c3: P::BaseField,
+    c4: P::BaseField,
+}
+
+/// g(x) = x^3 + A * x + B
+fn g<P: SVDWConfig>(x: P::BaseField) -> P::BaseField {
+    (x.square() + P::COEFF_A) * x + P::COEFF_B
+}
+
+impl<P: SVDWConfig> MapToCurve<Projective<P>> for SVDWMap<P> {
+    /// Checks that `P::ZETA` is a valid parameter and computes the constants
+    /// of the map.
+    fn new() -> Result<Self, HashToCurveError> {
+        let z = P::ZETA;
+        let g_z = g::<P>(z);
+        let three_z2_plus_4a = z.square() * P::BaseField::from(3u8) + P::COEFF_A.double().double();
+        if g_z.is_zero() || three_z2_plus_4a.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "g(ZETA) and 3 * ZETA^2 + 4 * A should be non-zero for the SVDW map".to_string(),
+            ));
+        }
+
+        let mut c3 = (-g_z * three_z2_plus_4a).sqrt().ok_or_else(|| {
+            HashToCurveError::MapToCurveError(
+                "-g(ZETA) * (3 * ZETA^2 + 4 * A) should be a square for the SVDW map".to_string(),
+            )
+        })?;
+        if parity(&c3) {
+            c3 = -c3;
+        }
+
+        let c2 = -z * P::BaseField::from(2u8).inverse().unwrap();
+        if g_z.legendre().is_qnr() && g::<P>(c2).legendre().is_qnr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "g(ZETA) or g(-ZETA / 2) should be a square for the SVDW map".to_string(),
+            ));
+        }
+
+        Ok(SVDWMap {
+            c1: g_z,
+            c2,
+            c3,
+            c4: -g_z.double().double() * three_z2_plus_4a.inverse().unwrap(),
+        })
+    }
+
+    /// Map an arbitrary base field element to a curve point.
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        // The exceptional case 1 - c1 * u^2 = 0 or 1 + c1 * u^2 = 0 is handled
+        // by inv0, which maps zero to zero.
+        let tv1 = point.square() * self.c1;
+        let tv2 = P::BaseField::one() + tv1;
+        let tv1 = P::BaseField::one() - tv1;
+        let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = point * tv1 * tv3 * self.c3;
+
+        let x1 = self.c2 - tv4;
+        let x2 = self.c2 + tv4;
+        let x3 = P::ZETA + self.c4 * (tv2.square() * tv3).square();
+
+        // At least one of g(x1), g(x2) and g(x3) is a square, and the first
+        // one is taken.
+        let (x, mut y) = [x1, x2, x3]
+            .into_iter()
+            .find_map(|x| g::<P>(x).sqrt().map(|y| (x, y)))
+            .expect("g(x3) is a square when g(x1) and g(x2) are not. Q.E.D.");
+        if parity(&y) != parity(&point) {
+            y = -y;
+        }
+
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
@@ -0,0 +1,346 @@
+/// Tests the endomorphism-based scalar multiplications of the short Weierstrass
+/// curve with the config `$config` against [`double_and_add`].
+///
+/// With `glv`, the config implements `GLVConfig`, and the eigenvalue of its
+/// endomorphism and its multi-scalar multiplication are tested as well. With
+/// `gls`, only the scalar multiplications are tested.
+///
+/// [`double_and_add`]: crate::scalar_mul::double_and_add
+#[macro_export]
+macro_rules! test_glv {
+    ($mod_name:ident; $config:ty; glv) => {
+        mod $mod_name {
+            use super::*;
+            use ark_ec::{scalar_mul::glv::GLVConfig, AffineRepr, VariableBaseMSM};
+
+            $crate::__test_endomorphism_mul!($config; <$config as GLVConfig>::LAMBDA);
+
+            #[test]
+            fn test_endomorphism_eigenvalue() {
+                let mut rng = test_rng();
+                for _ in 0..10 {
+                    let p = Projective::rand(&mut rng);
+                    let naive = naive_mul(&p, &<$config as GLVConfig>::LAMBDA);
+                    assert_eq!(<$config as GLVConfig>::endomorphism(&p), naive);
+                    assert_eq!(
+                        <$config as GLVConfig>::endomorphism_affine(&p.into_affine()),
+                        naive.into_affine()
+                    );
+                }
+            }
+
+            #[test]
+            fn test_msm() {
+                let mut rng = test_rng();
+                // Both sides of the 32-base threshold of the window size.
+                for size in [4, 40] {
+                    let mut scalars = test_scalars();
+                    scalars.truncate(size);
+                    scalars.resize_with(size, || Fr::rand(&mut rng));
+                    let bases: Vec<_> = (0..size)
+                        .map(|_| Projective::rand(&mut rng).into_affine())
+                        .collect();
+
+                    let naive: Projective = bases
+                        .iter()
+                        .zip(&scalars)
+                        .map(|(p, s)| naive_mul(&p.into_group(), s))
+                        .sum();
+                    assert_eq!(Projective::msm(&bases, &scalars).unwrap(), naive);
+                }
+                let generator = <$config as ark_ec::short_weierstrass::SWCurveConfig>::GENERATOR;
+                assert_eq!(Projective::msm(&[generator], &[]), Err(0));
+            }
+
+            #[test]
+            fn test_msm_against_generic_msm() {
+                let mut rng = test_rng();
+                // Several windows of more than 3 bits.
+                let size = 300;
+                let scalars: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+                let bases: Vec<_> = (0..size)
+                    .map(|_| Projective::rand(&mut rng).into_affine())
+                    .collect();
+                let bigints: Vec<_> = scalars.iter().map(|s| s.into_bigint()).collect();
+                assert_eq!(
+                    Projective::msm(&bases, &scalars).unwrap(),
+                    Projective::msm_bigint(&bases, &bigints)
+                );
+            }
+        }
+    };
+    ($mod_name:ident; $config:ty; gls) => {
+        mod $mod_name {
+            use super::*;
+
+            $crate::__test_endomorphism_mul!($config;);
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_endomorphism_mul {
+    ($config:ty; $($scalar:expr),*) => {
+        use ark_ec::{CurveGroup, Group};
+        use ark_ff::{One, PrimeField, UniformRand, Zero};
+        use ark_std::{test_rng, vec, vec::Vec};
+
+        type Projective = ark_ec::short_weierstrass::Projective<$config>;
+        type Fr = <$config as ark_ec::CurveConfig>::ScalarField;
+
+        fn naive_mul(p: &Projective, scalar: &Fr) -> Projective {
+            $crate::scalar_mul::double_and_add(p, scalar.into_bigint().as_ref())
+        }
+
+        fn test_scalars() -> Vec<Fr> {
+            let mut rng = test_rng();
+            let mut scalars = vec![Fr::zero(), Fr::one(), -Fr::one(), $($scalar),*];
+            scalars.extend((0..20).map(|_| Fr::rand(&mut rng)));
+            scalars
+        }
+
+        #[test]
+        fn test_mul() {
+            let mut rng = test_rng();
+            for s in test_scalars() {
+                let p = Projective::rand(&mut rng);
+                let naive = naive_mul(&p, &s);
+                assert_eq!(p * s, naive);
+                assert_eq!(p.into_affine() * s, naive);
+            }
+        }
+
+        #[test]
+        fn test_mul_large_scalars() {
+            use ark_ec::AffineRepr;
+
+            let mut rng = test_rng();
+            let p = Projective::rand(&mut rng);
+            // r, a scalar with more limbs than r, and a small scalar padded
+            // with a zero limb.
+            let mut long = Fr::rand(&mut rng).into_bigint().as_ref().to_vec();
+            long.push(1);
+            let mut padded = Fr::rand(&mut rng).into_bigint().as_ref().to_vec();
+            padded.push(0);
+            for scalar in [Fr::MODULUS.as_ref(), &long[..], &padded[..]] {
+                let naive = $crate::scalar_mul::double_and_add(&p, scalar);
+                assert_eq!(p.mul_bigint(scalar), naive);
+                assert_eq!(p.into_affine().mul_bigint(scalar), naive);
+            }
+            assert!(p.mul_bigint(Fr::MODULUS).is_zero());
+        }
+    };
+}
+
+/// Tests the SEC 1 encodings of the module `$sec1` of the short Weierstrass
+/// curve with the config `$config`, whose cofactor is 1, against `$keys`: an
+/// array of private keys, and the compressed and uncompressed encodings of the
+/// corresponding public keys, as hexadecimal strings. The crate of the
+/// curve must have `hex` as a dev-dependency.
+#[macro_export]
+macro_rules! test_sec1 {
+    ($mod_name:ident; $sec1:ident; $config:ty; $keys:expr) => {
+        mod $mod_name {
+            use super::*;
+            use ark_ec::{AffineRepr, CurveGroup};
+            use ark_ff::{BigInteger, PrimeField, UniformRand};
+            use ark_serialize::Compress;
+            use ark_std::{test_rng, vec};
+
+            type Affine = ark_ec::short_weierstrass::Affine<$config>;
+            type Projective = ark_ec::short_weierstrass::Projective<$config>;
+            type Fq = <$config as ark_ec::CurveConfig>::BaseField;
+            type Fr = <$config as ark_ec::CurveConfig>::ScalarField;
+
+            #[test]
+            fn test_sec1_known_public_keys() {
+                for (sk_hex, compressed, uncompressed) in $keys {
+                    let sk =
+                        $sec1::decode_scalar(&hex::decode(sk_hex).unwrap().try_into().unwrap())
+                            .unwrap();
+                    assert_eq!(hex::encode($sec1::encode_scalar(sk)), sk_hex);
+
+                    let pk = (Affine::generator() * sk).into_affine();
+                    assert_eq!(
+                        hex::encode($sec1::encode_point(&pk, Compress::Yes)),
+                        compressed
+                    );
+                    assert_eq!(
+                        hex::encode($sec1::encode_point(&pk, Compress::No)),
+                        uncompressed
+                    );
+                    assert_eq!(
+                        $sec1::decode_point(&hex::decode(compressed).unwrap()).unwrap(),
+                        pk
+                    );
+                    assert_eq!(
+                        $sec1::decode_point(&hex::decode(uncompressed).unwrap()).unwrap(),
+                        pk
+                    );
+                }
+            }
+
+            #[test]
+            fn test_sec1_round_trip() {
+                let mut rng = test_rng();
+                for _ in 0..20 {
+                    let p = Projective::rand(&mut rng).into_affine();
+                    for compress in [Compress::Yes, Compress::No] {
+                        assert_eq!(
+                            $sec1::decode_point(&$sec1::encode_point(&p, compress)).unwrap(),
+                            p
+                        );
+                    }
+                    let s = Fr::rand(&mut rng);
+                    assert_eq!($sec1::decode_scalar(&$sec1::encode_scalar(s)).unwrap(), s);
+                }
+
+                let zero = Affine::zero();
+                assert_eq!($sec1::encode_point(&zero, Compress::Yes), [0x00]);
+                assert_eq!($sec1::encode_point(&zero, Compress::No), [0x00]);
+                assert_eq!($sec1::decode_point(&[0x00]).unwrap(), zero);
+            }
+
+            #[test]
+            fn test_sec1_invalid_encodings() {
+                let g = Affine::generator();
+                let compressed = $sec1::encode_point(&g, Compress::Yes);
+                let uncompressed = $sec1::encode_point(&g, Compress::No);
+
+                // Wrong lengths and tags, including the hybrid encodings.
+                assert!($sec1::decode_point(&[]).is_err());
+                assert!($sec1::decode_point(&[0x00, 0x00]).is_err());
+                assert!($sec1::decode_point(&compressed[..compressed.len() - 1]).is_err());
+                assert!($sec1::decode_point(&uncompressed[1..]).is_err());
+                let mut bytes = uncompressed.clone();
+                bytes[0] = if compressed[0] == 0x02 { 0x06 } else { 0x07 };
+                assert!($sec1::decode_point(&bytes).is_err());
+
+                // Coordinates that are not smaller than p.
+                let mut bytes = vec![0x02];
+                bytes.extend(Fq::MODULUS.to_bytes_be());
+                assert!($sec1::decode_point(&bytes).is_err());
+                let mut bytes = uncompressed.clone();
+                bytes[1..=$sec1::FIELD_ENCODED_SIZE].copy_from_slice(&Fq::MODULUS.to_bytes_be());
+                assert!($sec1::decode_point(&bytes).is_err());
+
+                // Points that are not on the curve.
+                let mut bytes = uncompressed.clone();
+                *bytes.last_mut().unwrap() ^= 1;
+                assert!($sec1::decode_point(&bytes).is_err());
+                let x = (0u64..)
+                    .map(Fq::from)
+                    .find(|x| Affine::get_point_from_x_unchecked(*x, false).is_none())
+                    .unwrap();
+                let mut bytes = vec![0x02];
+                bytes.extend(x.into_bigint().to_bytes_be());
+                assert!($sec1::decode_point(&bytes).is_err());
+
+                // Scalars that are not smaller than n.
+                let n = Fr::MODULUS.to_bytes_be();
+                assert!($sec1::decode_scalar(&n.try_into().unwrap()).is_err());
+                assert!($sec1::decode_scalar(&[0xff; $sec1::FIELD_ENCODED_SIZE]).is_err());
+            }
+        }
+    };
+}
+
+/// Tests the hash-to-curve suite of `$field_hasher` and `$map` for the group
+/// `$group` against the vectors of the file `$path`, which follow the format
+/// of the vectors of RFC 9380, both step by step and end to end: msg to u, u
+/// to Q0 and Q1, and msg to P. The vectors of an encoding, which has a single
+/// Q and for which P = Q, set `randomOracle` to false. The DST of a vector
+/// may be given with the vector rather than for the whole file. Elements of
+/// extension fields are given as comma-separated coefficients.
+///
+/// By default, the coordinates of the vectors are the affine coordinates of
+/// the points of `$group`. Otherwise, `$to_affine` maps them to a point of
+/// `$group`. The crate of the curve must have `hex` and `serde_json` as
+/// dev-dependencies.
+#[macro_export]
+macro_rules! test_h2c_xmd {
+    ($mod_name:ident; $path:expr; $group:ty; $field_hasher:ty; $map:ty) => {
+        $crate::test_h2c_xmd!($mod_name; $path; $group; $field_hasher; $map; |x, y| {
+            <<$group as ark_ec::CurveGroup>::Affine>::new_unchecked(x, y)
+        });
+    };
+    ($mod_name:ident; $path:expr; $group:ty; $field_hasher:ty; $map:ty; $to_affine:expr) => {
+        mod $mod_name {
+            use super::*;
+            use ark_ec::{
+                hashing::{
+                    map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+                    HashToCurve,
+                },
+                AffineRepr, CurveGroup,
+            };
+            use ark_ff::{field_hashers::HashToField, Field, PrimeField};
+            use ark_std::{fs::File, io::BufReader, vec::Vec};
+            use serde_json::Value;
+
+            type Affine = <$group as CurveGroup>::Affine;
+            type F = <Affine as AffineRepr>::BaseField;
+
+            fn read_f(v: &Value) -> F {
+                let elems: Vec<_> = v
+                    .as_str()
+                    .unwrap()
+                    .split(',')
+                    .map(|s| {
+                        <F as Field>::BasePrimeField::from_be_bytes_mod_order(
+                            &hex::decode(&s[2..]).unwrap(),
+                        )
+                    })
+                    .collect();
+                F::from_base_prime_field_elems(&elems).unwrap()
+            }
+
+            // Q0 and Q1 are not necessarily in the prime-order subgroup.
+            fn read_point(v: &Value) -> Affine {
+                let to_affine: fn(F, F) -> Affine = $to_affine;
+                let p = to_affine(read_f(&v["x"]), read_f(&v["y"]));
+                assert!(p.is_on_curve());
+                p
+            }
+
+            #[test]
+            fn test_hash_to_curve_vectors() {
+                let file = File::open($path).unwrap();
+                let data: Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+                let random_oracle = data["randomOracle"].as_bool().unwrap_or(true);
+                let mapper = <$map as MapToCurve<$group>>::new().unwrap();
+                for v in data["vectors"].as_array().unwrap() {
+                    let dst = v.get("dst").unwrap_or(&data["dst"]).as_str().unwrap();
+                    let msg = v["msg"].as_str().unwrap().as_bytes();
+                    let field_hasher = <$field_hasher as HashToField<F>>::new(dst.as_bytes());
+                    if !random_oracle {
+                        let u: Vec<F> = field_hasher.hash_to_field(msg, 1);
+                        assert_eq!(u[0], read_f(&v["u"][0]));
+
+                        let q = mapper.map_to_curve(u[0]).unwrap();
+                        assert_eq!(q, read_point(&v["Q"]));
+                        assert_eq!(q, read_point(&v["P"]));
+                        continue;
+                    }
+
+                    let u: Vec<F> = field_hasher.hash_to_field(msg, 2);
+                    assert_eq!(u[0], read_f(&v["u"][0]));
+                    assert_eq!(u[1], read_f(&v["u"][1]));
+
+                    let q0 = mapper.map_to_curve(u[0]).unwrap();
+                    let q1 = mapper.map_to_curve(u[1]).unwrap();
+                    assert_eq!(q0, read_point(&v["Q0"]));
+                    assert_eq!(q1, read_point(&v["Q1"]));
+
+                    let hasher =
+                        MapToCurveBasedHasher::<$group, $field_hasher, $map>::new(dst.as_bytes())
+                            .unwrap();
+                    let p = hasher.hash(msg).unwrap();
+                    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+                    assert_eq!(p, read_point(&v["P"]));
+                }
+            }
+        }
+    };
+}
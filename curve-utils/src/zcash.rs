@@ -0,0 +1,67 @@
+//! The flags of the point encoding introduced by Zcash for BLS12-381, which
+//! is also used for BLS12-377, for a curve whose base field elements are
+//! encoded with [`FQ_SERIALIZED_SIZE`] bytes.
+//!
+//! Field elements are encoded as big-endian integers. The three most
+//! significant bits of the first byte of a point encoding, which are always
+//! zero in the encoding of a field element, are used as flags:
+//! - the compression flag is set for compressed points;
+//! - the infinity flag is set for the point at infinity;
+//! - the sort flag is set for compressed points whose y-coordinate is the
+//!   lexicographically largest of `y` and `-y`.
+
+use ark_ff::PrimeField;
+use ark_serialize::SerializationError;
+
+use crate::sec1::{decode_field_element, encode_field_element};
+
+/// The size of the encoding of a base field element.
+pub const FQ_SERIALIZED_SIZE: usize = 48;
+
+pub struct EncodingFlags {
+    pub is_compressed: bool,
+    pub is_infinity: bool,
+    pub is_lexicographically_largest: bool,
+}
+
+impl EncodingFlags {
+    /// Reads the flags from the first byte of `bytes`.
+    pub fn get_flags(bytes: &[u8]) -> Self {
+        Self {
+            is_compressed: (bytes[0] >> 7) & 1 == 1,
+            is_infinity: (bytes[0] >> 6) & 1 == 1,
+            is_lexicographically_largest: (bytes[0] >> 5) & 1 == 1,
+        }
+    }
+
+    /// Sets the flags in the first byte of `bytes`. The sort flag is only set
+    /// for compressed points other than the point at infinity.
+    pub fn encode_flags(&self, bytes: &mut [u8]) {
+        if self.is_compressed {
+            bytes[0] |= 1 << 7;
+        }
+
+        if self.is_infinity {
+            bytes[0] |= 1 << 6;
+        }
+
+        if self.is_compressed && !self.is_infinity && self.is_lexicographically_largest {
+            bytes[0] |= 1 << 5;
+        }
+    }
+}
+
+/// Encodes `field` as a big-endian integer.
+pub fn serialize_fq<F: PrimeField>(field: F) -> [u8; FQ_SERIALIZED_SIZE] {
+    encode_field_element::<F, FQ_SERIALIZED_SIZE>(field)
+}
+
+/// Decodes a big-endian integer of [`FQ_SERIALIZED_SIZE`] bytes, whose flags
+/// must have been masked away and which must be smaller than the modulus of
+/// `F`.
+pub fn deserialize_fq<F: PrimeField>(bytes: &[u8]) -> Result<F, SerializationError> {
+    if bytes.len() != FQ_SERIALIZED_SIZE {
+        return Err(SerializationError::InvalidData);
+    }
+    decode_field_element(bytes)
+}
@@ -1,4 +1,5 @@
 use crate::{Fq, Fr};
+use ark_curve_utils::elligator2::Elligator2Config;
 use ark_ec::{
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, MontgomeryAffine, Projective, TECurveConfig},
@@ -7,6 +8,9 @@ use ark_ff::MontFp;
 
 #[cfg(test)]
 mod tests;
+pub mod x25519;
+
+pub type Elligator2Map = ark_curve_utils::elligator2::Elligator2Map<Curve25519Config>;
 
 pub type EdwardsAffine = Affine<Curve25519Config>;
 pub type EdwardsProjective = Projective<Curve25519Config>;
@@ -59,6 +63,11 @@ impl MontCurveConfig for Curve25519Config {
     type TECurveConfig = Curve25519Config;
 }
 
+impl Elligator2Config for Curve25519Config {
+    /// Z = 2, the non-square used by the curve25519 suites of RFC 9380.
+    const Z: Fq = MontFp!("2");
+}
+
 /// GENERATOR_X =
 /// 38213832894368730265794714087330135568483813637251082400757400312561599933396
 const GENERATOR_X: Fq =
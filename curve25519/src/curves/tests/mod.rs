@@ -0,0 +1,132 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_h2c_xmd};
+use sha2::Sha512;
+
+test_group!(te; EdwardsProjective; te);
+
+// The vectors are given in the Montgomery form.
+test_h2c_xmd!(
+    h2c;
+    "./src/curves/tests/curve25519_XMD-SHA-512_ELL2_RO_.json";
+    EdwardsProjective;
+    XMDFieldHasher<Sha512, 128>;
+    Elligator2Map;
+    |s, t| EdwardsAffine::new_unchecked(s / t, (s - Fq::ONE) / (s + Fq::ONE))
+);
+
+/// Scalars, input u-coordinates and outputs of the test vectors of section 5.2
+/// of RFC 7748.
+const X25519_VECTORS: [(&str, &str, &str); 2] = [
+    (
+        "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+        "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+        "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+    ),
+    (
+        "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+        "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+        "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+    ),
+];
+
+#[test]
+fn test_x25519_vectors() {
+    let read = |s: &str| -> [u8; 32] { hex::decode(s).unwrap().try_into().unwrap() };
+    for (scalar, u, output) in X25519_VECTORS {
+        assert_eq!(hex::encode(x25519::x25519(read(scalar), read(u))), output);
+    }
+}
+
+// The iterated test of section 5.2 of RFC 7748, up to 1,000 iterations.
+#[test]
+fn test_x25519_iterated() {
+    let mut k = x25519::BASEPOINT;
+    let mut u = x25519::BASEPOINT;
+    for i in 1..=1000 {
+        (k, u) = (x25519::x25519(k, u), k);
+        if i == 1 {
+            assert_eq!(
+                hex::encode(k),
+                "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"
+            );
+        }
+    }
+    assert_eq!(
+        hex::encode(k),
+        "684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"
+    );
+}
+
+// The iterated test of section 5.2 of RFC 7748, up to 1,000,000 iterations.
+// It takes minutes, so it only runs with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn test_x25519_iterated_one_million() {
+    let mut k = x25519::BASEPOINT;
+    let mut u = x25519::BASEPOINT;
+    for _ in 0..1_000_000 {
+        (k, u) = (x25519::x25519(k, u), k);
+    }
+    assert_eq!(
+        hex::encode(k),
+        "7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424"
+    );
+}
+
+// The Diffie-Hellman test of section 6.1 of RFC 7748.
+#[test]
+fn test_x25519_diffie_hellman() {
+    let read = |s: &str| -> [u8; 32] { hex::decode(s).unwrap().try_into().unwrap() };
+    let alice = read("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob = read("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+    let alice_public = x25519::x25519(alice, x25519::BASEPOINT);
+    let bob_public = x25519::x25519(bob, x25519::BASEPOINT);
+    assert_eq!(
+        hex::encode(alice_public),
+        "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+    );
+    assert_eq!(
+        hex::encode(bob_public),
+        "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"
+    );
+
+    let shared = "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742";
+    assert_eq!(hex::encode(x25519::x25519(alice, bob_public)), shared);
+    assert_eq!(hex::encode(x25519::x25519(bob, alice_public)), shared);
+}
+
+#[test]
+fn test_ladder_agrees_with_scalar_multiplication() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, Field, PrimeField, Zero};
+    use ark_std::{test_rng, UniformRand};
+
+    // u = (1 + y) / (1 - y) on the birationally equivalent twisted Edwards
+    // curve, and the identity has u = 0 in the ladder.
+    let to_u = |p: EdwardsAffine| {
+        if p.is_zero() {
+            Fq::zero()
+        } else {
+            (Fq::ONE + p.y) * (Fq::ONE - p.y).inverse().unwrap()
+        }
+    };
+
+    let mut rng = test_rng();
+    let g = EdwardsAffine::generator();
+    assert_eq!(to_u(g), Fq::from(9u64));
+    for _ in 0..10 {
+        let p = EdwardsProjective::rand(&mut rng).into_affine();
+        let k = Fr::rand(&mut rng);
+        let bytes = k.into_bigint().to_bytes_le();
+        assert_eq!(
+            x25519::ladder::<Curve25519Config>(&bytes, to_u(p)),
+            to_u((p * k).into_affine())
+        );
+    }
+    assert!(
+        x25519::ladder::<Curve25519Config>(&Fr::MODULUS.to_bytes_le(), Fq::from(9u64)).is_zero()
+    );
+    assert!(x25519::ladder::<Curve25519Config>(&[], Fq::from(9u64)).is_zero());
+}
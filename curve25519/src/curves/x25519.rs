@@ -0,0 +1,112 @@
+//! The x-only Montgomery ladder, and the X25519 function of [RFC 7748].
+//!
+//! The ladder only works with the u-coordinates of points of a Montgomery
+//! curve, and performs the same sequence of field operations for every
+//! scalar of a given length: the conditional swaps are computed with field
+//! arithmetic rather than with branches. Whether the whole computation runs
+//! in constant time also depends on the field arithmetic.
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use ark_ec::twisted_edwards::MontCurveConfig;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+
+use crate::{Curve25519Config, Fq};
+
+pub const ENCODED_SIZE: usize = 32;
+
+/// The u-coordinate of the base point of X25519.
+pub const BASEPOINT: [u8; ENCODED_SIZE] = {
+    let mut result = [0u8; ENCODED_SIZE];
+    result[0] = 9;
+    result
+};
+
+/// Computes the u-coordinate of `[k]P`, where `P` is a point whose
+/// u-coordinate is `u` and `k` is the little-endian integer `scalar`. The
+/// point at infinity has u-coordinate 0.
+///
+/// The result does not depend on which of the two points with u-coordinate
+/// `u` is chosen, nor on whether they lie on the curve or on its quadratic
+/// twist.
+pub fn ladder<P: MontCurveConfig>(scalar: &[u8], u: P::BaseField) -> P::BaseField
+where
+    P::BaseField: PrimeField,
+{
+    // a24 = (A - 2) / 4
+    let a24 = (P::COEFF_A - P::BaseField::from(2u64)) / P::BaseField::from(4u64);
+
+    // (x2 : z2) and (x3 : z3) are the projective u-coordinates of [m]P and
+    // [m + 1]P, where m is given by the bits of the scalar processed so far.
+    let (mut x2, mut z2) = (P::BaseField::one(), P::BaseField::zero());
+    let (mut x3, mut z3) = (u, P::BaseField::one());
+    let mut swap = false;
+    for byte in scalar.iter().rev() {
+        for i in (0..8).rev() {
+            let bit = (byte >> i) & 1 == 1;
+            swap ^= bit;
+            conditional_swap(&mut x2, &mut x3, swap);
+            conditional_swap(&mut z2, &mut z3, swap);
+            swap = bit;
+
+            let a = x2 + z2;
+            let aa = a.square();
+            let b = x2 - z2;
+            let bb = b.square();
+            let e = aa - bb;
+            let da = (x3 - z3) * a;
+            let cb = (x3 + z3) * b;
+            x3 = (da + cb).square();
+            z3 = u * (da - cb).square();
+            x2 = aa * bb;
+            z2 = e * (aa + a24 * e);
+        }
+    }
+    conditional_swap(&mut x2, &mut x3, swap);
+    conditional_swap(&mut z2, &mut z3, swap);
+
+    // z2^(p - 2) is the inverse of z2, or 0 when z2 = 0.
+    let mut exponent = P::BaseField::MODULUS;
+    exponent.sub_with_borrow(&2u64.into());
+    x2 * z2.pow(exponent)
+}
+
+/// Swaps `a` and `b` when `swap` is set, without branching on it.
+fn conditional_swap<F: Field>(a: &mut F, b: &mut F, swap: bool) {
+    let d = (*a - *b) * F::from(swap);
+    *a -= d;
+    *b += d;
+}
+
+/// Clamps a 32-byte little-endian integer, as done to the secret scalars of
+/// X25519 and Ed25519: the three least significant bits and the most
+/// significant bit are cleared, and the second most significant bit is set.
+///
+/// The result is a multiple of the cofactor 8, in the range [2^254, 2^255).
+pub fn clamp_integer(mut bytes: [u8; ENCODED_SIZE]) -> [u8; ENCODED_SIZE] {
+    bytes[0] &= 0b1111_1000;
+    bytes[31] &= 0b0111_1111;
+    bytes[31] |= 0b0100_0000;
+    bytes
+}
+
+/// The X25519 function of section 5 of RFC 7748. The scalar is clamped, and
+/// the most significant bit of `u` is ignored. Non-canonical encodings of
+/// `u` are accepted and reduced modulo p, and the result is encoded
+/// canonically.
+///
+/// The result is all zeros when `u` is the u-coordinate of a point of small
+/// order, which callers performing a key exchange may want to reject.
+pub fn x25519(scalar: [u8; ENCODED_SIZE], u: [u8; ENCODED_SIZE]) -> [u8; ENCODED_SIZE] {
+    let mut u_bytes = u;
+    u_bytes[31] &= 0x7f;
+    let u = Fq::from_le_bytes_mod_order(&u_bytes);
+
+    let mut result = [0u8; ENCODED_SIZE];
+    result.copy_from_slice(
+        &ladder::<Curve25519Config>(&clamp_integer(scalar), u)
+            .into_bigint()
+            .to_bytes_le(),
+    );
+    result
+}
@@ -0,0 +1,235 @@
+//! The Ed25519 signature scheme of [RFC 8032].
+//!
+//! Signatures are verified either with the cofactorless equation
+//! `[S]B = R + [k]A` of RFC 8032, which also requires the encodings of A and
+//! R to be canonical, or with the cofactored equation
+//! `[8][S]B = [8]R + [8][k]A` and the encoding rules of [ZIP 215]. The two
+//! only disagree on signatures involving points of small order or
+//! non-canonical encodings, which an honest signer never produces. Batch
+//! verification is only consistent with the cofactored equation.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+//! [ZIP 215]: https://zips.z.cash/zip-0215
+
+use ark_ec::{scalar_mul::variable_base::VariableBaseMSM, AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::SerializationError;
+use ark_std::{rand::Rng, vec::Vec};
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::rfc8032;
+use crate::{EdwardsAffine, EdwardsProjective, Fr};
+
+pub const SECRET_KEY_SIZE: usize = 32;
+pub const PUBLIC_KEY_SIZE: usize = 32;
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// A secret key, expanded from its 32-byte seed. Its secret parts are
+/// zeroized when it is dropped.
+#[derive(Clone)]
+pub struct SigningKey {
+    seed: [u8; SECRET_KEY_SIZE],
+    scalar: Fr,
+    prefix: [u8; 32],
+    verifying_key: VerifyingKey,
+}
+
+/// A public key, which keeps the encoding it was decoded from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    point: EdwardsAffine,
+    bytes: [u8; PUBLIC_KEY_SIZE],
+}
+
+/// A signature `R || S`. Its encodings are only decoded when it is verified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r_bytes: [u8; 32],
+    s_bytes: [u8; 32],
+}
+
+impl Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.scalar.zeroize();
+        self.prefix.zeroize();
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SigningKey {}
+
+impl SigningKey {
+    /// Expands `seed` as specified in section 5.1.5 of RFC 8032.
+    pub fn from_seed(seed: &[u8; SECRET_KEY_SIZE]) -> Self {
+        let h = Sha512::digest(seed);
+        let scalar = rfc8032::clamped_scalar(h[..32].try_into().unwrap());
+        let point = (EdwardsAffine::generator() * scalar).into_affine();
+
+        Self {
+            seed: *seed,
+            scalar,
+            prefix: h[32..].try_into().unwrap(),
+            verifying_key: VerifyingKey {
+                point,
+                bytes: rfc8032::encode_point(&point),
+            },
+        }
+    }
+
+    pub fn to_seed(&self) -> [u8; SECRET_KEY_SIZE] {
+        self.seed
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `message` deterministically, as specified in section 5.1.6 of
+    /// RFC 8032.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let r = hash_to_scalar(&[&self.prefix, message]);
+        let r_bytes = rfc8032::encode_point(&(EdwardsAffine::generator() * r).into_affine());
+        let k = hash_to_scalar(&[&r_bytes, &self.verifying_key.bytes, message]);
+
+        Signature {
+            r_bytes,
+            s_bytes: rfc8032::encode_scalar(r + k * self.scalar),
+        }
+    }
+}
+
+impl VerifyingKey {
+    /// Decodes a public key, accepting the non-canonical encodings allowed by
+    /// ZIP 215. These are rejected by [`VerifyingKey::verify`].
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_SIZE]) -> Result<Self, SerializationError> {
+        Ok(Self {
+            point: rfc8032::decode_point_lenient(bytes)?,
+            bytes: *bytes,
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
+        self.bytes
+    }
+
+    pub fn point(&self) -> EdwardsAffine {
+        self.point
+    }
+
+    /// Verifies `signature` with the cofactorless equation of section 5.1.7 of
+    /// RFC 8032, requiring the encodings of A and R to be canonical.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        let (r, s) = match (
+            rfc8032::decode_point(&signature.r_bytes),
+            rfc8032::decode_scalar(&signature.s_bytes),
+        ) {
+            (Ok(r), Ok(s)) => (r, s),
+            _ => return false,
+        };
+        if rfc8032::decode_point(&self.bytes).is_err() {
+            return false;
+        }
+
+        let k = hash_to_scalar(&[&signature.r_bytes, &self.bytes, message]);
+        EdwardsAffine::generator() * s - self.point * k == r
+    }
+
+    /// Verifies `signature` with the cofactored equation and the encoding
+    /// rules of ZIP 215.
+    pub fn verify_zip215(&self, message: &[u8], signature: &Signature) -> bool {
+        let (r, s) = match (
+            rfc8032::decode_point_lenient(&signature.r_bytes),
+            rfc8032::decode_scalar(&signature.s_bytes),
+        ) {
+            (Ok(r), Ok(s)) => (r, s),
+            _ => return false,
+        };
+
+        let k = hash_to_scalar(&[&signature.r_bytes, &self.bytes, message]);
+        (EdwardsAffine::generator() * s - r - self.point * k)
+            .into_affine()
+            .mul_by_cofactor()
+            .is_zero()
+    }
+}
+
+impl Signature {
+    pub fn from_bytes(bytes: &[u8; SIGNATURE_SIZE]) -> Self {
+        Self {
+            r_bytes: bytes[..32].try_into().unwrap(),
+            s_bytes: bytes[32..].try_into().unwrap(),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut result = [0u8; SIGNATURE_SIZE];
+        result[..32].copy_from_slice(&self.r_bytes);
+        result[32..].copy_from_slice(&self.s_bytes);
+        result
+    }
+}
+
+/// Verifies a batch of signatures with the cofactored equation and the
+/// encoding rules of ZIP 215, by checking a random linear combination of
+/// their equations with a single MSM.
+///
+/// It returns `true` exactly when [`VerifyingKey::verify_zip215`] accepts
+/// each signature, except with probability at most 2^-128 over the choice of
+/// the coefficients.
+///
+/// # Panics
+///
+/// Panics if `messages`, `signatures` and `keys` have different lengths.
+pub fn verify_batch<R: Rng + ?Sized>(
+    messages: &[&[u8]],
+    signatures: &[Signature],
+    keys: &[VerifyingKey],
+    rng: &mut R,
+) -> bool {
+    assert_eq!(messages.len(), signatures.len());
+    assert_eq!(messages.len(), keys.len());
+
+    // sum_i z_i * ([S_i]B - R_i - [k_i]A_i) = 0
+    let mut bases = Vec::with_capacity(2 * messages.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * messages.len() + 1);
+    let mut b_coeff = Fr::zero();
+    for ((message, signature), key) in messages.iter().zip(signatures).zip(keys) {
+        let (r, s) = match (
+            rfc8032::decode_point_lenient(&signature.r_bytes),
+            rfc8032::decode_scalar(&signature.s_bytes),
+        ) {
+            (Ok(r), Ok(s)) => (r, s),
+            _ => return false,
+        };
+        let k = hash_to_scalar(&[&signature.r_bytes, &key.bytes, message]);
+
+        let z = Fr::from(rng.gen::<u128>());
+        b_coeff += z * s;
+        bases.extend([r, key.point]);
+        scalars.extend([-z, -z * k]);
+    }
+    bases.push(EdwardsAffine::generator());
+    scalars.push(b_coeff);
+
+    EdwardsProjective::msm_unchecked(&bases, &scalars)
+        .into_affine()
+        .mul_by_cofactor()
+        .is_zero()
+}
+
+/// SHA-512 of the concatenation of `inputs`, as a little-endian integer
+/// reduced modulo L.
+fn hash_to_scalar(inputs: &[&[u8]]) -> Fr {
+    let mut h = Sha512::new();
+    for input in inputs {
+        h.update(input);
+    }
+    Fr::from_le_bytes_mod_order(&h.finalize())
+}
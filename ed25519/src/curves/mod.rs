@@ -1,13 +1,19 @@
 use crate::{Fq, Fr};
+use ark_curve_utils::elligator2::Elligator2Config;
 use ark_ec::{
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
 
+pub mod eddsa;
+pub mod rfc8032;
+pub mod ristretto255;
 #[cfg(test)]
 mod tests;
 
+pub type Elligator2Map = ark_curve_utils::elligator2::Elligator2Map<EdwardsConfig>;
+
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
@@ -60,6 +66,22 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// The suites `edwards25519_XMD:SHA-512_ELL2_RO_` and
+// `edwards25519_XMD:SHA-512_ELL2_NU_` of RFC 9380 map to curve25519, whose
+// COEFF_B is 1, and then to ed25519. Mapping to the Montgomery form above
+// directly would give a different point for about half of the inputs.
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 2, the non-square used by the curve25519 suites of RFC 9380.
+    const Z: Fq = MontFp!("2");
+
+    /// COEFF_K = 1
+    const COEFF_K: Fq = MontFp!("1");
+
+    /// EDWARDS_SCALE = sqrt(-486664), the square root with sgn0 equal to 0.
+    const EDWARDS_SCALE: Fq =
+        MontFp!("6853475219497561581579357271197624642482790079785650197046958215289687604742");
+}
+
 /// GENERATOR_X =
 /// 15112221349535400772501151409588531511454012693041857206046113283949847762202
 const GENERATOR_X: Fq =
@@ -0,0 +1,98 @@
+//! The point and scalar encodings of [RFC 8032], and the clamping of secret
+//! scalars used by Ed25519 and X25519.
+//!
+//! A point is encoded as the 32-byte little-endian encoding of its
+//! y-coordinate, whose most significant bit is replaced by the least
+//! significant bit of its x-coordinate. A scalar is encoded as a 32-byte
+//! little-endian integer.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::SerializationError;
+
+use crate::{EdwardsAffine, Fq, Fr};
+
+pub const ENCODED_SIZE: usize = 32;
+
+pub fn encode_point(p: &EdwardsAffine) -> [u8; ENCODED_SIZE] {
+    let mut result = [0u8; ENCODED_SIZE];
+    result.copy_from_slice(&p.y.into_bigint().to_bytes_le());
+    if p.x.into_bigint().is_odd() {
+        result[31] |= 1 << 7;
+    }
+    result
+}
+
+/// Decodes a point as specified in section 5.1.3 of RFC 8032. The encoding of
+/// y must be smaller than p, and the encoding of x = 0 with its sign bit set
+/// is rejected.
+///
+/// The point is on the curve, but it is not checked to be in the prime-order
+/// subgroup.
+pub fn decode_point(bytes: &[u8; ENCODED_SIZE]) -> Result<EdwardsAffine, SerializationError> {
+    decode_point_with_mode(bytes, true)
+}
+
+/// Decodes a point like [`decode_point`], but accepts the non-canonical
+/// encodings of y, which are reduced modulo p, and the encoding of x = 0 with
+/// its sign bit set. These are the encodings accepted by ZIP 215.
+pub fn decode_point_lenient(
+    bytes: &[u8; ENCODED_SIZE],
+) -> Result<EdwardsAffine, SerializationError> {
+    decode_point_with_mode(bytes, false)
+}
+
+fn decode_point_with_mode(
+    bytes: &[u8; ENCODED_SIZE],
+    strict: bool,
+) -> Result<EdwardsAffine, SerializationError> {
+    let mut y_bytes = *bytes;
+    let sign = y_bytes[31] >> 7 == 1;
+    y_bytes[31] &= 0x7f;
+
+    let y = Fq::from_le_bytes_mod_order(&y_bytes);
+    if strict && y.into_bigint().to_bytes_le() != y_bytes {
+        return Err(SerializationError::InvalidData);
+    }
+
+    // x^2 = (y^2 - 1) / (d * y^2 + 1), where d * y^2 + 1 is never zero.
+    let (x, _) =
+        EdwardsAffine::get_xs_from_y_unchecked(y).ok_or(SerializationError::InvalidData)?;
+    if x.is_zero() && sign && strict {
+        return Err(SerializationError::InvalidData);
+    }
+    let x = if x.into_bigint().is_odd() == sign {
+        x
+    } else {
+        -x
+    };
+
+    Ok(EdwardsAffine::new_unchecked(x, y))
+}
+
+/// Encodes `s` as a 32-byte little-endian integer.
+pub fn encode_scalar(s: Fr) -> [u8; ENCODED_SIZE] {
+    let mut result = [0u8; ENCODED_SIZE];
+    result.copy_from_slice(&s.into_bigint().to_bytes_le());
+    result
+}
+
+/// Decodes a 32-byte little-endian integer, which must be smaller than the
+/// group order L.
+pub fn decode_scalar(bytes: &[u8; ENCODED_SIZE]) -> Result<Fr, SerializationError> {
+    let s = Fr::from_le_bytes_mod_order(bytes);
+    if s.into_bigint().to_bytes_le() != bytes {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(s)
+}
+
+pub use ark_curve25519::x25519::clamp_integer;
+
+/// Clamps `bytes` with [`clamp_integer`] and reduces the result modulo L. The
+/// multiples of a point of the prime-order subgroup, such as the generator, by
+/// the clamped integer and by this scalar agree.
+pub fn clamped_scalar(bytes: [u8; ENCODED_SIZE]) -> Fr {
+    Fr::from_le_bytes_mod_order(&clamp_integer(bytes))
+}
@@ -0,0 +1,223 @@
+//! The ristretto255 group of [RFC 9496], a prime-order group built from the
+//! points of ed25519.
+//!
+//! An element is represented by a point of ed25519, and two points represent
+//! the same element when they differ by a point of the 4-torsion subgroup.
+//! The encoding, decoding and equality of elements only depend on this
+//! class, so that the 8-torsion of ed25519 is not observable. Since every
+//! decoded point lies in the prime-order subgroup plus the 4-torsion, the
+//! group has order L.
+//!
+//! [RFC 9496]: https://www.rfc-editor.org/rfc/rfc9496
+
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ff::{BigInteger, Field, MontFp, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, SerializationError, Valid, Validate};
+use ark_std::io::Read;
+
+use crate::{EdwardsConfig, EdwardsProjective, Fq, Fr};
+
+pub const ENCODED_SIZE: usize = 32;
+pub const UNIFORM_BYTES_SIZE: usize = 64;
+
+/// SQRT_M1 = sqrt(-1)
+const SQRT_M1: Fq =
+    MontFp!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
+
+/// SQRT_AD_MINUS_ONE = sqrt(a * d - 1)
+const SQRT_AD_MINUS_ONE: Fq =
+    MontFp!("25063068953384623474111414158702152701244531502492656460079210482610430750235");
+
+/// INVSQRT_A_MINUS_D = 1 / sqrt(a - d)
+const INVSQRT_A_MINUS_D: Fq =
+    MontFp!("54469307008909316920995813868745141605393597292927456921205312896311721017578");
+
+/// ONE_MINUS_D_SQ = 1 - d^2
+const ONE_MINUS_D_SQ: Fq =
+    MontFp!("1159843021668779879193775521855586647937357759715417654439879720876111806838");
+
+/// D_MINUS_ONE_SQ = (d - 1)^2
+const D_MINUS_ONE_SQ: Fq =
+    MontFp!("40440834346308536858101042469323190826248399146238708352240133220865137265952");
+
+/// An element of ristretto255, represented by a point of ed25519.
+#[derive(Copy, Clone)]
+#[must_use]
+pub struct RistrettoPoint(EdwardsProjective);
+
+impl RistrettoPoint {
+    /// Encodes `self` as specified in section 4.3.2 of RFC 9496.
+    pub fn encode(&self) -> [u8; ENCODED_SIZE] {
+        let EdwardsProjective {
+            x: x0,
+            y: y0,
+            t: t0,
+            z: z0,
+        } = self.0;
+
+        let u1 = (z0 + y0) * (z0 - y0);
+        let u2 = x0 * y0;
+        let (_, invsqrt) = sqrt_ratio_m1(Fq::one(), u1 * u2.square());
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t0;
+
+        let (x, mut y, den_inv) = if is_negative(t0 * z_inv) {
+            (y0 * SQRT_M1, x0 * SQRT_M1, den1 * INVSQRT_A_MINUS_D)
+        } else {
+            (x0, y0, den2)
+        };
+        if is_negative(x * z_inv) {
+            y = -y;
+        }
+
+        let mut result = [0u8; ENCODED_SIZE];
+        result.copy_from_slice(&abs(den_inv * (z0 - y)).into_bigint().to_bytes_le());
+        result
+    }
+
+    /// Decodes an element as specified in section 4.3.1 of RFC 9496, which
+    /// rejects the non-canonical encodings.
+    pub fn decode(bytes: &[u8; ENCODED_SIZE]) -> Result<Self, SerializationError> {
+        let s = Fq::from_le_bytes_mod_order(bytes);
+        if s.into_bigint().to_bytes_le() != bytes || is_negative(s) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let ss = s.square();
+        let u1 = Fq::one() - ss;
+        let u2 = Fq::one() + ss;
+        let u2_sqr = u2.square();
+        let v = -(EdwardsConfig::COEFF_D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = sqrt_ratio_m1(Fq::one(), v * u2_sqr);
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+        let x = abs(s.double() * den_x);
+        let y = u1 * den_y;
+        let t = x * y;
+        if !was_square || is_negative(t) || y.is_zero() {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok(Self(EdwardsProjective::new_unchecked(x, y, t, Fq::one())))
+    }
+
+    /// Maps 64 uniformly random bytes to an element, as specified in section
+    /// 4.3.4 of RFC 9496. The result is uniformly distributed, and its
+    /// discrete logarithm with respect to any other element is unknown.
+    pub fn from_uniform_bytes(bytes: &[u8; UNIFORM_BYTES_SIZE]) -> Self {
+        let map = |half: &[u8]| {
+            let mut t_bytes = [0u8; 32];
+            t_bytes.copy_from_slice(half);
+            t_bytes[31] &= 0x7f;
+            elligator(Fq::from_le_bytes_mod_order(&t_bytes))
+        };
+        Self(map(&bytes[..32]) + map(&bytes[32..]))
+    }
+}
+
+/// The Elligator map of section 4.3.4 of RFC 9496, from a field element to a
+/// point of ed25519.
+fn elligator(t: Fq) -> EdwardsProjective {
+    let d = EdwardsConfig::COEFF_D;
+    let r = SQRT_M1 * t.square();
+    let u = (r + Fq::one()) * ONE_MINUS_D_SQ;
+    let v = (-Fq::one() - r * d) * (r + d);
+
+    let (was_square, s) = sqrt_ratio_m1(u, v);
+    let (s, c) = if was_square {
+        (s, -Fq::one())
+    } else {
+        (-abs(s * t), r)
+    };
+    let n = c * (r - Fq::one()) * D_MINUS_ONE_SQ - v;
+
+    let w0 = s.double() * v;
+    let w1 = n * SQRT_AD_MINUS_ONE;
+    let w2 = Fq::one() - s.square();
+    let w3 = Fq::one() + s.square();
+    EdwardsProjective::new_unchecked(w0 * w3, w2 * w1, w0 * w2, w1 * w3)
+}
+
+/// Returns whether `u / v` is a square, together with the nonnegative
+/// square root of `u / v` if it is, and of `SQRT_M1 * u / v` otherwise. The
+/// root is 0 when `u` or `v` is 0.
+fn sqrt_ratio_m1(u: Fq, v: Fq) -> (bool, Fq) {
+    // (p - 5) / 8
+    let mut exponent = Fq::MODULUS;
+    exponent.sub_with_borrow(&5u64.into());
+    exponent.divn(3);
+
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    let mut r = u * v3 * (u * v7).pow(exponent);
+    let check = v * r.square();
+
+    let correct_sign_sqrt = check == u;
+    let flipped_sign_sqrt = check == -u;
+    let flipped_sign_sqrt_i = check == -u * SQRT_M1;
+    if flipped_sign_sqrt || flipped_sign_sqrt_i {
+        r *= SQRT_M1;
+    }
+    (correct_sign_sqrt || flipped_sign_sqrt, abs(r))
+}
+
+/// A field element is negative when its canonical encoding is odd.
+fn is_negative(x: Fq) -> bool {
+    x.into_bigint().is_odd()
+}
+
+fn abs(x: Fq) -> Fq {
+    if is_negative(x) {
+        -x
+    } else {
+        x
+    }
+}
+
+ark_curve_utils::impl_group_wrapper!(RistrettoPoint; EdwardsProjective; Fr; ENCODED_SIZE);
+
+/// Two points of ed25519 represent the same element when they differ by a
+/// point of the 4-torsion subgroup, as checked in section 4.3.3 of RFC 9496.
+impl PartialEq for RistrettoPoint {
+    fn eq(&self, other: &Self) -> bool {
+        let (p, q) = (&self.0, &other.0);
+        p.x * q.y == p.y * q.x || p.y * q.y == p.x * q.x
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl Zero for RistrettoPoint {
+    fn zero() -> Self {
+        Self(EdwardsProjective::zero())
+    }
+
+    /// The identity is represented by the points of the 4-torsion subgroup,
+    /// which are those with x = 0 or y = 0.
+    fn is_zero(&self) -> bool {
+        self.0.x.is_zero() || self.0.y.is_zero()
+    }
+}
+
+/// Every representation is a valid element.
+impl Valid for RistrettoPoint {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+/// The encoding is always decoded strictly, as a valid encoding is always
+/// that of an element.
+impl CanonicalDeserialize for RistrettoPoint {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        reader.read_exact(&mut bytes)?;
+        Self::decode(&bytes)
+    }
+}
@@ -0,0 +1,360 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_h2c_xmd};
+use sha2::Sha512;
+
+test_group!(te; EdwardsProjective; te);
+
+test_h2c_xmd!(
+    h2c;
+    "./src/curves/tests/edwards25519_XMD-SHA-512_ELL2_RO_.json";
+    EdwardsProjective;
+    XMDFieldHasher<Sha512, 128>;
+    Elligator2Map
+);
+
+/// Secret keys and public keys of tests 1, 2, 3, 1024 and SHA(abc) of section
+/// 7.1 of RFC 8032, as vendored in the `ed25519_tests.txt` of BoringSSL.
+const RFC8032_KEYS: [(&str, &str); 5] = [
+    (
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+    ),
+    (
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+    ),
+    (
+        "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+    ),
+    (
+        "f5e5767cf153319517630f226876b86c8160cc583bc013744c6bf255f5cc0ee5",
+        "278117fc144c72340f67d0f2316e8386ceffbf2b2428c9c51fef7c597f1d426e",
+    ),
+    (
+        "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+    ),
+];
+
+#[test]
+fn test_rfc8032_public_keys() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use sha2::{Digest, Sha512};
+
+    assert_eq!(
+        hex::encode(rfc8032::encode_point(&EdwardsAffine::generator())),
+        "5866666666666666666666666666666666666666666666666666666666666666"
+    );
+
+    for (sk, pk) in RFC8032_KEYS {
+        let h = Sha512::digest(hex::decode(sk).unwrap());
+        let s = rfc8032::clamped_scalar(h[..32].try_into().unwrap());
+        let a = (EdwardsAffine::generator() * s).into_affine();
+        assert_eq!(hex::encode(rfc8032::encode_point(&a)), pk);
+
+        let bytes = hex::decode(pk).unwrap().try_into().unwrap();
+        assert_eq!(rfc8032::decode_point(&bytes).unwrap(), a);
+        assert_eq!(rfc8032::decode_point_lenient(&bytes).unwrap(), a);
+    }
+}
+
+#[test]
+fn test_rfc8032_round_trip() {
+    use ark_ec::CurveGroup;
+    use ark_std::{test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        let p = EdwardsProjective::rand(&mut rng).into_affine();
+        assert_eq!(
+            rfc8032::decode_point(&rfc8032::encode_point(&p)).unwrap(),
+            p
+        );
+        let s = Fr::rand(&mut rng);
+        assert_eq!(
+            rfc8032::decode_scalar(&rfc8032::encode_scalar(s)).unwrap(),
+            s
+        );
+    }
+}
+
+#[test]
+fn test_rfc8032_non_canonical_encodings() {
+    use ark_ff::{BigInteger, PrimeField};
+
+    // y = p + 1 is a non-canonical encoding of the identity, as is x = 0
+    // with its sign bit set.
+    let mut p_plus_one = [0u8; 32];
+    p_plus_one.copy_from_slice(&Fq::MODULUS.to_bytes_le());
+    p_plus_one[0] += 1;
+    let mut negative_zero = [0u8; 32];
+    negative_zero[0] = 1;
+    negative_zero[31] = 0x80;
+    for bytes in [p_plus_one, negative_zero] {
+        assert!(rfc8032::decode_point(&bytes).is_err());
+        assert!(rfc8032::decode_point_lenient(&bytes).unwrap().is_zero());
+    }
+
+    // y = 2 is not the y-coordinate of a point.
+    let mut bytes = [0u8; 32];
+    bytes[0] = 2;
+    assert!(rfc8032::decode_point_lenient(&bytes).is_err());
+
+    let mut l = [0u8; 32];
+    l.copy_from_slice(&Fr::MODULUS.to_bytes_le());
+    assert!(rfc8032::decode_scalar(&l).is_err());
+}
+
+#[test]
+fn test_clamp_integer() {
+    let clamped = rfc8032::clamp_integer([0xff; 32]);
+    assert_eq!(clamped[0], 0xf8);
+    assert_eq!(clamped[1..31], [0xff; 30]);
+    assert_eq!(clamped[31], 0x7f);
+    let clamped = rfc8032::clamp_integer([0x00; 32]);
+    assert_eq!(clamped[..31], [0x00; 31]);
+    assert_eq!(clamped[31], 0x40);
+}
+
+/// Messages and signatures of tests 1, 2, 3, 1024 and SHA(abc) of section 7.1
+/// of RFC 8032, signed with the keys of `RFC8032_KEYS`. The message of test
+/// 1024 has 1023 bytes, and the one of test SHA(abc) is SHA-512("abc").
+const RFC8032_SIGNATURES: [(&str, &str); 5] = [
+    (
+        "",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    ),
+    (
+        "72",
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+    ),
+    (
+        "af82",
+        "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+    ),
+    (
+        "08b8b2b733424243760fe426a4b54908632110a66c2f6591eabd3345e3e4eb98fa6e264bf09efe12ee50f8f54e9f77b1e355f6c50544e23fb1433ddf73be84d879de7c0046dc4996d9e773f4bc9efe5738829adb26c81b37c93a1b270b20329d658675fc6ea534e0810a4432826bf58c941efb65d57a338bbd2e26640f89ffbc1a858efcb8550ee3a5e1998bd177e93a7363c344fe6b199ee5d02e82d522c4feba15452f80288a821a579116ec6dad2b3b310da903401aa62100ab5d1a36553e06203b33890cc9b832f79ef80560ccb9a39ce767967ed628c6ad573cb116dbefefd75499da96bd68a8a97b928a8bbc103b6621fcde2beca1231d206be6cd9ec7aff6f6c94fcd7204ed3455c68c83f4a41da4af2b74ef5c53f1d8ac70bdcb7ed185ce81bd84359d44254d95629e9855a94a7c1958d1f8ada5d0532ed8a5aa3fb2d17ba70eb6248e594e1a2297acbbb39d502f1a8c6eb6f1ce22b3de1a1f40cc24554119a831a9aad6079cad88425de6bde1a9187ebb6092cf67bf2b13fd65f27088d78b7e883c8759d2c4f5c65adb7553878ad575f9fad878e80a0c9ba63bcbcc2732e69485bbc9c90bfbd62481d9089beccf80cfe2df16a2cf65bd92dd597b0707e0917af48bbb75fed413d238f5555a7a569d80c3414a8d0859dc65a46128bab27af87a71314f318c782b23ebfe808b82b0ce26401d2e22f04d83d1255dc51addd3b75a2b1ae0784504df543af8969be3ea7082ff7fc9888c144da2af58429ec96031dbcad3dad9af0dcbaaaf268cb8fcffead94f3c7ca495e056a9b47acdb751fb73e666c6c655ade8297297d07ad1ba5e43f1bca32301651339e22904cc8c42f58c30c04aafdb038dda0847dd988dcda6f3bfd15c4b4c4525004aa06eeff8ca61783aacec57fb3d1f92b0fe2fd1a85f6724517b65e614ad6808d6f6ee34dff7310fdc82aebfd904b01e1dc54b2927094b2db68d6f903b68401adebf5a7e08d78ff4ef5d63653a65040cf9bfd4aca7984a74d37145986780fc0b16ac451649de6188a7dbdf191f64b5fc5e2ab47b57f7f7276cd419c17a3ca8e1b939ae49e488acba6b965610b5480109c8b17b80e1b7b750dfc7598d5d5011fd2dcc5600a32ef5b52a1ecc820e308aa342721aac0943bf6686b64b2579376504ccc493d97e6aed3fb0f9cd71a43dd497f01f17c0e2cb3797aa2a2f256656168e6c496afc5fb93246f6b1116398a346f1a641f3b041e989f7914f90cc2c7fff357876e506b50d334ba77c225bc307ba537152f3f1610e4eafe595f6d9d90d11faa933a15ef1369546868a7f3a45a96768d40fd9d03412c091c6315cf4fde7cb68606937380db2eaaa707b4c4185c32eddcdd306705e4dc1ffc872eeee475a64dfac86aba41c0618983f8741c5ef68d3a101e8a3b8cac60c905c15fc910840b94c00a0b9d0",
+        "0aab4c900501b3e24d7cdf4663326a3a87df5e4843b2cbdb67cbf6e460fec350aa5371b1508f9f4528ecea23c436d94b5e8fcd4f681e30a6ac00a9704a188a03",
+    ),
+    (
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+    ),
+];
+
+#[test]
+fn test_eddsa_rfc8032_vectors() {
+    use eddsa::{Signature, SigningKey, VerifyingKey};
+
+    for ((sk, pk), (message, signature)) in RFC8032_KEYS.into_iter().zip(RFC8032_SIGNATURES) {
+        let signing_key = SigningKey::from_seed(&hex::decode(sk).unwrap().try_into().unwrap());
+        assert_eq!(hex::encode(signing_key.to_seed()), sk);
+        let verifying_key = *signing_key.verifying_key();
+        assert_eq!(hex::encode(verifying_key.to_bytes()), pk);
+        assert_eq!(
+            VerifyingKey::from_bytes(&verifying_key.to_bytes()).unwrap(),
+            verifying_key
+        );
+
+        let message = hex::decode(message).unwrap();
+        let signature_bytes = hex::decode(signature).unwrap().try_into().unwrap();
+        let signature = Signature::from_bytes(&signature_bytes);
+        assert_eq!(signing_key.sign(&message), signature);
+        assert_eq!(signature.to_bytes(), signature_bytes);
+        assert!(verifying_key.verify(&message, &signature));
+        assert!(verifying_key.verify_zip215(&message, &signature));
+
+        let mut tampered = message.clone();
+        tampered.push(0);
+        assert!(!verifying_key.verify(&tampered, &signature));
+        assert!(!verifying_key.verify_zip215(&tampered, &signature));
+    }
+}
+
+#[test]
+fn test_eddsa_non_canonical_s() {
+    use ark_ff::{BigInteger, PrimeField};
+    use eddsa::{Signature, SigningKey};
+
+    // S + L satisfies the verification equation, but is rejected by both.
+    let signing_key = SigningKey::from_seed(&[7u8; 32]);
+    let mut bytes = signing_key.sign(b"message").to_bytes();
+    let mut s = Fr::MODULUS;
+    s.add_with_carry(&Fr::from_le_bytes_mod_order(&bytes[32..]).into_bigint());
+    bytes[32..].copy_from_slice(&s.to_bytes_le());
+    let signature = Signature::from_bytes(&bytes);
+    assert!(!signing_key.verifying_key().verify(b"message", &signature));
+    assert!(!signing_key
+        .verifying_key()
+        .verify_zip215(b"message", &signature));
+}
+
+#[test]
+fn test_eddsa_signing_key_zeroize() {
+    use eddsa::SigningKey;
+    use zeroize::Zeroize;
+
+    let mut signing_key = SigningKey::from_seed(&[7u8; 32]);
+    let verifying_key = *signing_key.verifying_key();
+    signing_key.zeroize();
+    assert_eq!(signing_key.to_seed(), [0u8; 32]);
+    // The prefix and the scalar are zero as well, so that the signature no
+    // longer verifies.
+    let signature = signing_key.sign(b"message");
+    assert!(!verifying_key.verify(b"message", &signature));
+}
+
+// The small-order vectors of ed25519-zebra 4.0.3: every combination of an
+// encoding of a point of small order as A and R, with S = 0, is a valid
+// signature of "Zcash" under ZIP 215. Only those with canonical encodings
+// satisfying the cofactorless equation are valid under RFC 8032.
+#[test]
+fn test_eddsa_zip215_small_order() {
+    use ark_std::{fs::File, io::BufReader, test_rng, vec::Vec};
+    use eddsa::{Signature, VerifyingKey};
+    use serde_json::Value;
+
+    let file = File::open("./src/curves/tests/zip215_small_order.json").unwrap();
+    let data: Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let read_hex = |v: &Value| hex::decode(v.as_str().unwrap()).unwrap();
+
+    let mut keys = Vec::new();
+    let mut signatures = Vec::new();
+    let mut valid_cofactorless_count = 0;
+    for v in data.as_array().unwrap() {
+        let key = VerifyingKey::from_bytes(&read_hex(&v["vk_bytes"]).try_into().unwrap()).unwrap();
+        let signature = Signature::from_bytes(&read_hex(&v["sig_bytes"]).try_into().unwrap());
+        assert_eq!(
+            key.verify_zip215(b"Zcash", &signature),
+            v["valid_zip215"].as_bool().unwrap()
+        );
+        // The legacy rules of libsodium also reject an all-zero A and the R
+        // of small order, which the cofactorless equation of RFC 8032 accepts.
+        let valid_cofactorless = key.verify(b"Zcash", &signature);
+        assert!(valid_cofactorless || !v["valid_legacy"].as_bool().unwrap());
+        valid_cofactorless_count += valid_cofactorless as usize;
+        keys.push(key);
+        signatures.push(signature);
+    }
+    assert_eq!(keys.len(), 196);
+    assert_eq!(valid_cofactorless_count, 8);
+
+    let messages = [b"Zcash".as_slice(); 196];
+    assert!(eddsa::verify_batch(
+        &messages,
+        &signatures,
+        &keys,
+        &mut test_rng()
+    ));
+}
+
+#[test]
+fn test_eddsa_verify_batch() {
+    use ark_std::{test_rng, vec::Vec};
+    use eddsa::SigningKey;
+
+    let mut rng = test_rng();
+    let signing_keys: Vec<_> = (0..8u8).map(|i| SigningKey::from_seed(&[i; 32])).collect();
+    let keys: Vec<_> = signing_keys.iter().map(|k| *k.verifying_key()).collect();
+    let messages: Vec<&[u8]> = (0..8).map(|i| &b"batch verification"[..10 + i]).collect();
+    let mut signatures: Vec<_> = signing_keys
+        .iter()
+        .zip(&messages)
+        .map(|(k, m)| k.sign(m))
+        .collect();
+    assert!(eddsa::verify_batch(&messages, &signatures, &keys, &mut rng));
+    assert!(eddsa::verify_batch(&[], &[], &[], &mut rng));
+
+    signatures.swap(2, 5);
+    assert!(!eddsa::verify_batch(
+        &messages,
+        &signatures,
+        &keys,
+        &mut rng
+    ));
+    signatures.swap(2, 5);
+    let mut bytes = signatures[3].to_bytes();
+    bytes[0] ^= 1;
+    signatures[3] = eddsa::Signature::from_bytes(&bytes);
+    assert!(!eddsa::verify_batch(
+        &messages,
+        &signatures,
+        &keys,
+        &mut rng
+    ));
+}
+
+// The test vectors of appendix A of RFC 9496.
+#[test]
+fn test_ristretto255_rfc_vectors() {
+    use ark_ec::Group;
+    use ark_ff::Zero;
+    use ark_serialize::CanonicalDeserialize;
+    use ark_std::{fs::File, io::BufReader};
+    use ristretto255::RistrettoPoint;
+    use serde_json::Value;
+
+    let file = File::open("./src/curves/tests/ristretto255.json").unwrap();
+    let data: Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let read_hex = |v: &Value| hex::decode(v.as_str().unwrap()).unwrap();
+
+    let mut p = RistrettoPoint::zero();
+    for (i, v) in data["multiples_of_generator"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .enumerate()
+    {
+        let bytes = read_hex(v);
+        assert_eq!(p.encode().as_slice(), bytes);
+        assert_eq!(RistrettoPoint::generator() * Fr::from(i as u64), p);
+        let decoded = RistrettoPoint::decode(&bytes.try_into().unwrap()).unwrap();
+        assert_eq!(decoded, p);
+        assert_eq!(decoded.is_zero(), i == 0);
+        p += RistrettoPoint::generator();
+    }
+
+    for v in data["invalid_encodings"].as_array().unwrap() {
+        let bytes = read_hex(v);
+        assert!(RistrettoPoint::decode(&bytes.clone().try_into().unwrap()).is_err());
+        assert!(RistrettoPoint::deserialize_compressed(bytes.as_slice()).is_err());
+    }
+
+    for v in data["one_way_map"].as_array().unwrap() {
+        let p = RistrettoPoint::from_uniform_bytes(&read_hex(&v["input"]).try_into().unwrap());
+        assert_eq!(p.encode().as_slice(), read_hex(&v["output"]));
+    }
+}
+
+#[test]
+fn test_ristretto255_group() {
+    use ark_ec::Group;
+    use ark_ff::Zero;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{test_rng, vec::Vec, UniformRand};
+    use ristretto255::RistrettoPoint;
+
+    let mut rng = test_rng();
+    let g = RistrettoPoint::generator();
+    for _ in 0..10 {
+        let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        assert_eq!(g * a + g * b, g * (a + b));
+        assert_eq!(g * a - g * b, g * (a - b));
+        assert_eq!((g * a).double(), g * (a + a));
+        assert!((g * a + -(g * a)).is_zero());
+
+        let p = RistrettoPoint::rand(&mut rng);
+        assert_eq!(RistrettoPoint::decode(&p.encode()).unwrap(), p);
+        let mut bytes = Vec::new();
+        p.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), ristretto255::ENCODED_SIZE);
+        assert_eq!(
+            RistrettoPoint::deserialize_compressed(bytes.as_slice()).unwrap(),
+            p
+        );
+        assert!((p * -Fr::from(1u64) + p).is_zero());
+    }
+    assert!((g * Fr::zero()).is_zero());
+}
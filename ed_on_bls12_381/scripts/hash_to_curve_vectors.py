@@ -0,0 +1,217 @@
+# Computes the known answers of the hash-to-curve tests of ed_on_bls12_381
+# (Jubjub) and ed_on_bls12_381_bandersnatch (Bandersnatch), independently of
+# arkworks, following RFC 9380:
+# - the Elligator 2 map (Section 6.7.1) to the Montgomery form, followed by
+#   the rational map (s, t) -> (s / t, (s - 1) / (s + 1)) to the twisted
+#   Edwards form, as done by `Elligator2Map` of ark-ec;
+# - the simplified SWU map (Section 6.6.2) to the short Weierstrass form;
+# - hash_to_field (Section 5.2) with expand_message_xmd (Section 5.3.1)
+#   instantiated with SHA-256 and L = 48, followed by clear_cofactor as a
+#   multiplication by the cofactor.
+#
+# The parameters are the ones of the configurations of the crates.
+#
+# Usage: python3 hash_to_curve_vectors.py {jubjub,bandersnatch}
+
+import hashlib
+import sys
+
+p = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+
+CURVES = {
+    "jubjub": dict(
+        # Twisted Edwards form: a * x^2 + y^2 = 1 + d * x^2 * y^2.
+        a=-1,
+        d=19257038036680949359750312669786877991949435402254120286184196891950884077233,
+        # Montgomery form: K * t^2 = s^3 + J * s^2 + s, and the Elligator 2 Z.
+        J=40962,
+        K=-40964,
+        Z=5,
+        # Short Weierstrass form: y^2 = x^3 + A * x + B, and the SWU Z.
+        A=52296097456646850916096512823759002727550416093741407922227928430486925478210,
+        B=48351165704696163914533707656614864561753505123260775585269522553028192119009,
+        ZETA=7,
+        h=8,
+        r=6554484396890773809930967563523245729705921265872317281365359162392183254199,
+    ),
+    "bandersnatch": dict(
+        a=-5,
+        d=45022363124591815672509500913686876175488063829319466900776701791074614335719,
+        J=29978822694968839326280996386011761570173833766074948509196803838190355340952,
+        K=25465760566081946422412445027709227188579564747101592991722834452325077642517,
+        Z=5,
+        A=10773120815616481058602537765553212789256758185246796157495669123169359657269,
+        B=29569587568322301171008055308580903175558631321415017492731745847794083609535,
+        ZETA=10,
+        h=4,
+        r=13108968793781547619861935127046491459309155893440570251786403306729687672801,
+    ),
+}
+
+# The inputs of the map vectors, and the DST and message of the hash vectors.
+MAP_INPUTS = [0, 1, 2, 3, 5, 1234567, -7]
+DST = b"domain"
+MSG = b"abc"
+
+
+def inv(a):
+    return pow(a, p - 2, p)
+
+
+def is_square(a):
+    return a % p == 0 or pow(a, (p - 1) // 2, p) == 1
+
+
+def sqrt(a):
+    # Tonelli-Shanks.
+    a %= p
+    if a == 0:
+        return 0
+    q, s = p - 1, 0
+    while q % 2 == 0:
+        q //= 2
+        s += 1
+    z = 2
+    while is_square(z):
+        z += 1
+    m, c, t, r = s, pow(z, q, p), pow(a, q, p), pow(a, (q + 1) // 2, p)
+    while t != 1:
+        i, t2 = 0, t
+        while t2 != 1:
+            t2 = t2 * t2 % p
+            i += 1
+        b = pow(c, 1 << (m - i - 1), p)
+        m, c, t, r = i, b * b % p, t * b * b % p, r * b % p
+    assert r * r % p == a
+    return r
+
+
+def sgn0(x):
+    return x % p % 2
+
+
+def expand_message_xmd(msg, dst, n):
+    h, b_len, s_len = hashlib.sha256, 32, 64
+    ell = -(-n // b_len)
+    dst_prime = dst + bytes([len(dst)])
+    b_0 = h(bytes(s_len) + msg + n.to_bytes(2, "big") + b"\0" + dst_prime).digest()
+    b_i = h(b_0 + b"\1" + dst_prime).digest()
+    out = b_i
+    for i in range(2, ell + 1):
+        b_i = h(bytes(x ^ y for x, y in zip(b_0, b_i)) + bytes([i]) + dst_prime).digest()
+        out += b_i
+    return out[:n]
+
+
+def hash_to_field(msg, dst, count=2, L=48):
+    u = expand_message_xmd(msg, dst, count * L)
+    return [int.from_bytes(u[i * L : (i + 1) * L], "big") % p for i in range(count)]
+
+
+def map_to_curve_simple_swu(C, u):
+    A, B, Z = C["A"], C["B"], C["ZETA"]
+    tv1 = (Z * Z * pow(u, 4, p) + Z * u * u) % p
+    if tv1 == 0:
+        x1 = B * inv(Z * A) % p
+    else:
+        x1 = -B * inv(A) * (1 + inv(tv1)) % p
+    gx1 = (x1**3 + A * x1 + B) % p
+    if is_square(gx1):
+        x, y = x1, sqrt(gx1)
+    else:
+        x = Z * u * u * x1 % p
+        y = sqrt(x**3 + A * x + B)
+    if sgn0(u) != sgn0(y):
+        y = -y % p
+    return (x, y)
+
+
+def map_to_curve_elligator2(C, u):
+    J, K, Z = C["J"], C["K"], C["Z"]
+    j = J * inv(K) % p
+    k2 = inv(K * K)
+
+    def g(x):
+        return x * ((x + j) * x + k2) % p
+
+    den = (1 + Z * u * u) % p
+    x1 = -j * (inv(den) if den else 1) % p
+    if is_square(g(x1)):
+        x, sign = x1, 1
+    else:
+        x, sign = (-x1 - j) % p, 0
+    y = sqrt(g(x))
+    if sgn0(y) != sign:
+        y = -y % p
+    s, t = x * K % p, y * K % p
+    if t * (s + 1) % p == 0:
+        return (0, 1)
+    return (s * inv(t) % p, (s - 1) * inv(s + 1) % p)
+
+
+def sw_add(C, P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2:
+        if (y1 + y2) % p == 0:
+            return None
+        l = (3 * x1 * x1 + C["A"]) * inv(2 * y1) % p
+    else:
+        l = (y2 - y1) * inv(x2 - x1) % p
+    x3 = (l * l - x1 - x2) % p
+    return (x3, (l * (x1 - x3) - y1) % p)
+
+
+def te_add(C, P, Q):
+    (x1, y1), (x2, y2) = P, Q
+    t = C["d"] * x1 * x2 * y1 * y2 % p
+    return (
+        (x1 * y2 + y1 * x2) * inv(1 + t) % p,
+        (y1 * y2 - C["a"] * x1 * x2) * inv(1 - t) % p,
+    )
+
+
+def mul(add, identity, P, k):
+    R = identity
+    for bit in bin(k)[2:]:
+        R = add(R, R)
+        if bit == "1":
+            R = add(R, P)
+    return R
+
+
+def main():
+    C = CURVES[sys.argv[1]]
+
+    print("Elligator 2 (u, x, y):")
+    for u in MAP_INPUTS:
+        print(u, *map_to_curve_elligator2(C, u % p))
+
+    print("SWU (u, x, y):")
+    for u in MAP_INPUTS:
+        print(u, *map_to_curve_simple_swu(C, u % p))
+
+    u0, u1 = hash_to_field(MSG, DST)
+
+    def te(P, Q):
+        return te_add(C, P, Q)
+
+    q = te(map_to_curve_elligator2(C, u0), map_to_curve_elligator2(C, u1))
+    P = mul(te, (0, 1), q, C["h"])
+    assert mul(te, (0, 1), P, C["r"]) == (0, 1)
+    print("Twisted Edwards hash (x, y):", *P)
+
+    def sw(P, Q):
+        return sw_add(C, P, Q)
+
+    q = sw(map_to_curve_simple_swu(C, u0), map_to_curve_simple_swu(C, u1))
+    P = mul(sw, None, q, C["h"])
+    assert mul(sw, None, P, C["r"]) is None
+    print("Short Weierstrass hash (x, y):", *P)
+
+
+if __name__ == "__main__":
+    main()
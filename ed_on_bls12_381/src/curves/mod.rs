@@ -1,4 +1,6 @@
+use ark_curve_utils::elligator2::Elligator2Config;
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self, SWCurveConfig},
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
@@ -10,6 +12,8 @@ use crate::{Fq, Fr};
 #[cfg(test)]
 mod tests;
 
+pub type Elligator2Map = ark_curve_utils::elligator2::Elligator2Map<JubjubConfig>;
+
 pub type EdwardsAffine = Affine<JubjubConfig>;
 pub type EdwardsProjective = Projective<JubjubConfig>;
 pub type SWAffine = short_weierstrass::Affine<JubjubConfig>;
@@ -96,6 +100,11 @@ impl MontCurveConfig for JubjubConfig {
     type TECurveConfig = JubjubConfig;
 }
 
+impl Elligator2Config for JubjubConfig {
+    /// Z = 5, the non-square of smallest absolute value in the base field.
+    const Z: Fq = MontFp!("5");
+}
+
 const GENERATOR_X: Fq =
     MontFp!("8076246640662884909881801758704306714034609987455869804520522091855516602923");
 
@@ -122,3 +131,9 @@ const SW_GENERATOR_X: Fq =
 /// y coordinate for SW curve generator
 const SW_GENERATOR_Y: Fq =
     MontFp!("43777270878440091394432848052353307184915192688165709016756678962558652055320");
+
+impl SWUConfig for JubjubConfig {
+    /// ZETA = 7, as found by the procedure of appendix H.2 of RFC 9380 for
+    /// the short Weierstrass form.
+    const ZETA: Fq = MontFp!("7");
+}
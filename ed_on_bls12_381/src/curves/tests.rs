@@ -1,5 +1,191 @@
 use crate::*;
 use ark_algebra_test_templates::*;
+use ark_ff::MontFp;
 
 test_group!(sw; SWProjective; sw);
 test_group!(te; EdwardsProjective; te);
+
+#[test]
+fn test_elligator2_map_to_curve() {
+    use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+    use ark_ff::{UniformRand, Zero};
+
+    let mut rng = ark_std::test_rng();
+    let mapper = Elligator2Map::new().unwrap();
+    assert!(mapper.map_to_curve(Fq::zero()).unwrap().is_on_curve());
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        // Elligator 2 only depends on u^2.
+        assert_eq!(mapper.map_to_curve(-u).unwrap(), p);
+    }
+}
+
+/// (u, x, y), where (x, y) is the image of u by the Elligator 2 map with Z = 5.
+/// They were computed by `scripts/hash_to_curve_vectors.py jubjub`, an
+/// implementation of RFC 9380 independent of arkworks.
+const ELLIGATOR2_VECTORS: [(Fq, Fq, Fq); 6] = [
+    (
+        MontFp!("1"),
+        MontFp!("47633601603280006610789313726622991585680456048568348353623238627991706927331"),
+        MontFp!("26717253715073086798640381114484440255418655376037961375185397737823965039517"),
+    ),
+    (
+        MontFp!("2"),
+        MontFp!("8939167122236275985389874463189730541952005857336263231270313870381924952488"),
+        MontFp!("37039776997744301034797114274119785350284819088817060790642578578984972713323"),
+    ),
+    (
+        MontFp!("3"),
+        MontFp!("44260805531083380121254299476863610158554612822789761564576345743465962251396"),
+        MontFp!("38195298262769111864538578016081105822331831721715849977145357412576240923435"),
+    ),
+    (
+        MontFp!("5"),
+        MontFp!("20525608415216714677211989217593389846605207500664133145096041594078796332644"),
+        MontFp!("45193776634923631112612466326920199177768554359108892663443000577995844435551"),
+    ),
+    (
+        MontFp!("1234567"),
+        MontFp!("6226471072601264128277395387299588216821974392730750572797087414692069536066"),
+        MontFp!("34351737092256095777742093132901992055613782543400609204114987164593239078797"),
+    ),
+    (
+        MontFp!("-7"),
+        MontFp!("26539727098671468952276052723348811099250768496304958411689897813835067592633"),
+        MontFp!("39516219517476471870305886377669176141165559311479272004832760181474492215975"),
+    ),
+];
+
+#[test]
+fn test_elligator2_vectors() {
+    use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+    use ark_ff::Zero;
+
+    let mapper = Elligator2Map::new().unwrap();
+    // The exceptional case x2 = 0 is mapped to the identity.
+    assert!(mapper.map_to_curve(Fq::zero()).unwrap().is_zero());
+    for (u, x, y) in ELLIGATOR2_VECTORS {
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        assert_eq!(p, EdwardsAffine::new_unchecked(x, y));
+    }
+}
+
+#[test]
+fn test_swu_map_to_curve() {
+    use ark_ec::hashing::{curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurve};
+    use ark_ff::UniformRand;
+
+    let mut rng = ark_std::test_rng();
+    let mapper = SWUMap::<SWConfig>::new().unwrap();
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        // The sign of the y-coordinate follows the one of u.
+        assert_eq!(mapper.map_to_curve(-u).unwrap(), -p);
+    }
+}
+
+/// (u, x, y), where (x, y) is the image of u by the simplified SWU map of the
+/// short Weierstrass form with Z = 7, computed by the same script. u = 0
+/// is the exceptional case of the map.
+const SWU_VECTORS: [(Fq, Fq, Fq); 7] = [
+    (
+        MontFp!("0"),
+        MontFp!("21980065193028755746825504685899997577879128028193463429302104250430169658054"),
+        MontFp!("30774329520414557319124080095793121947178948000621732763896975280931045004972"),
+    ),
+    (
+        MontFp!("1"),
+        MontFp!("40026567406393329601797306018659889148950784676100214900986227340454720628289"),
+        MontFp!("49542384692304696617703078657542999265988392515068265531025113483390602071211"),
+    ),
+    (
+        MontFp!("2"),
+        MontFp!("9849190696554223488813806469508900076137704148709712334036988357070761661561"),
+        MontFp!("5488031465517385510038998387804260455537478488144393061206631058571340755778"),
+    ),
+    (
+        MontFp!("3"),
+        MontFp!("39435130725852996690112967665981475973960647614683227117952618418376594029078"),
+        MontFp!("25252151095641693561348752996405512671452841879386844138569892982665331181143"),
+    ),
+    (
+        MontFp!("5"),
+        MontFp!("52399571221739274830136961167222572116165536125407078332459765862163017717849"),
+        MontFp!("27464917719048240532084097038724333016145603446119603173646456243228537505681"),
+    ),
+    (
+        MontFp!("1234567"),
+        MontFp!("18219635014643102134990468449503988921098682824882110980839537783632188275103"),
+        MontFp!("44044002116925184379670873977700124673872563465749333420225813871021601333145"),
+    ),
+    (
+        MontFp!("-7"),
+        MontFp!("48158387181935925931943835470812005856875024457637333935826659337985428137810"),
+        MontFp!("51895390999161032368633005753710530622954166299085273988315525664433117114310"),
+    ),
+];
+
+#[test]
+fn test_swu_vectors() {
+    use ark_ec::hashing::{curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurve};
+
+    let mapper = SWUMap::<SWConfig>::new().unwrap();
+    for (u, x, y) in SWU_VECTORS {
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        assert_eq!(p, SWAffine::new_unchecked(x, y));
+    }
+}
+
+/// The outputs for "abc" of `MapToCurveBasedHasher` with
+/// `XMDFieldHasher<Sha256>` and the DST "domain", with the Elligator 2 map to
+/// the twisted Edwards form and with the SWU map to the short Weierstrass
+/// form, computed by the same script. The cofactor is cleared by
+/// multiplying by it.
+const HASH_ABC_TE: (Fq, Fq) = (
+    MontFp!("35670184640452195110151032838022871436438477393915310151570867146595235076308"),
+    MontFp!("20363895822318259599441501743352142534238191479941418697391172762611294493516"),
+);
+const HASH_ABC_SW: (Fq, Fq) = (
+    MontFp!("35982032050415609542214655649528004951716739242918296765728998495983198387694"),
+    MontFp!("5221489803993145106814853356510727971611240079974845694795636190522794683543"),
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    use ark_curve_utils::field_hashers::XMDFieldHasher;
+    use ark_ec::hashing::{
+        curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
+    };
+    use sha2::Sha256;
+
+    let te_hasher =
+        MapToCurveBasedHasher::<EdwardsProjective, XMDFieldHasher<Sha256>, Elligator2Map>::new(
+            b"domain",
+        )
+        .unwrap();
+    let sw_hasher =
+        MapToCurveBasedHasher::<SWProjective, XMDFieldHasher<Sha256>, SWUMap<SWConfig>>::new(
+            b"domain",
+        )
+        .unwrap();
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+        let p = te_hasher.hash(msg).unwrap();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+
+        let p = sw_hasher.hash(msg).unwrap();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    let (x, y) = HASH_ABC_TE;
+    assert_eq!(te_hasher.hash(b"abc").unwrap(), EdwardsAffine::new(x, y));
+    let (x, y) = HASH_ABC_SW;
+    assert_eq!(sw_hasher.hash(b"abc").unwrap(), SWAffine::new(x, y));
+}
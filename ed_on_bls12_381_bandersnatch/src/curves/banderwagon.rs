@@ -0,0 +1,150 @@
+//! The Banderwagon group used by the Ethereum Verkle trees: the quotient of
+//! the points of Bandersnatch by the 2-torsion subgroup {(0, 1), (0, -1)}.
+//!
+//! An element is represented by a point of Bandersnatch, and the points P
+//! and P + (0, -1) represent the same element. The elements are those
+//! represented by points of the prime-order subgroup, so that the group has
+//! prime order r.
+//!
+//! An element is encoded as the 32-byte big-endian encoding of the
+//! x-coordinate of its representative whose y-coordinate is lexicographically
+//! largest, which is what the Verkle specification calls the compressed
+//! serialization.
+
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, SerializationError, Valid, Validate};
+use ark_std::io::Read;
+
+use crate::{BandersnatchConfig, EdwardsAffine, EdwardsProjective, Fq, Fr};
+
+pub const ENCODED_SIZE: usize = 32;
+
+/// An element of Banderwagon, represented by a point of Bandersnatch.
+#[derive(Copy, Clone)]
+#[must_use]
+pub struct Banderwagon(EdwardsProjective);
+
+impl Banderwagon {
+    /// Encodes the x-coordinate of the representative of `self` whose
+    /// y-coordinate is lexicographically largest.
+    pub fn encode(&self) -> [u8; ENCODED_SIZE] {
+        let p = EdwardsAffine::from(self.0);
+        let x = if is_positive(p.y) { p.x } else { -p.x };
+
+        let mut result = [0u8; ENCODED_SIZE];
+        result.copy_from_slice(&x.into_bigint().to_bytes_be());
+        result
+    }
+
+    /// Decodes an element, which fails unless the encoding of x is smaller
+    /// than q and the element passes [`Banderwagon::subgroup_check`].
+    pub fn decode(bytes: &[u8; ENCODED_SIZE]) -> Result<Self, SerializationError> {
+        let p = decode_unchecked(bytes)?;
+        if !p.subgroup_check() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(p)
+    }
+
+    /// Checks that `self` is represented by a point of the prime-order
+    /// subgroup, which is the case exactly when 1 - a * x^2 is a square.
+    pub fn subgroup_check(&self) -> bool {
+        let x = EdwardsAffine::from(self.0).x;
+        (Fq::one() - BandersnatchConfig::COEFF_A * x.square())
+            .legendre()
+            .is_qr()
+    }
+
+    /// Maps `self` to x / y, which does not depend on the representative. As
+    /// a is not a square, y is never zero.
+    pub fn map_to_field(&self) -> Fq {
+        self.0.x * self.0.y.inverse().unwrap()
+    }
+
+    /// Reduces the little-endian encoding of [`Banderwagon::map_to_field`]
+    /// modulo r. This is used to commit to an element in a Verkle tree.
+    pub fn map_to_scalar_field(&self) -> Fr {
+        Fr::from_le_bytes_mod_order(&self.map_to_field().into_bigint().to_bytes_le())
+    }
+}
+
+/// Decodes an element without checking that it is in the subgroup.
+fn decode_unchecked(bytes: &[u8; ENCODED_SIZE]) -> Result<Banderwagon, SerializationError> {
+    let x = Fq::from_be_bytes_mod_order(bytes);
+    if x.into_bigint().to_bytes_be() != bytes {
+        return Err(SerializationError::InvalidData);
+    }
+
+    // y^2 = (1 - a * x^2) / (1 - d * x^2), where 1 - d * x^2 is never zero
+    // as d is not a square.
+    let x2 = x.square();
+    let y2 = (Fq::one() - BandersnatchConfig::COEFF_A * x2)
+        * (Fq::one() - BandersnatchConfig::COEFF_D * x2)
+            .inverse()
+            .unwrap();
+    let y = y2.sqrt().ok_or(SerializationError::InvalidData)?;
+    let y = if is_positive(y) { y } else { -y };
+
+    Ok(Banderwagon(EdwardsProjective::new_unchecked(
+        x,
+        y,
+        x * y,
+        Fq::one(),
+    )))
+}
+
+/// A field element is lexicographically largest when it is larger than its
+/// negation.
+fn is_positive(y: Fq) -> bool {
+    y > -y
+}
+
+ark_curve_utils::impl_group_wrapper!(Banderwagon; EdwardsProjective; Fr; ENCODED_SIZE);
+
+/// The points (x1, y1) and (x2, y2) represent the same element when
+/// x1 * y2 = x2 * y1.
+impl PartialEq for Banderwagon {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.x * other.0.y == other.0.x * self.0.y
+    }
+}
+
+impl Eq for Banderwagon {}
+
+impl Zero for Banderwagon {
+    fn zero() -> Self {
+        Self(EdwardsProjective::zero())
+    }
+
+    /// The identity is represented by (0, 1) and (0, -1).
+    fn is_zero(&self) -> bool {
+        self.0.x.is_zero()
+    }
+}
+
+impl Valid for Banderwagon {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.subgroup_check() {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl CanonicalDeserialize for Banderwagon {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        _compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        reader.read_exact(&mut bytes)?;
+        let p = decode_unchecked(&bytes)?;
+        if validate == Validate::Yes {
+            p.check()?;
+        }
+        Ok(p)
+    }
+}
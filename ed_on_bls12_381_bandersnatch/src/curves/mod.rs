@@ -1,4 +1,6 @@
+use ark_curve_utils::elligator2::Elligator2Config;
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self, SWCurveConfig},
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
@@ -7,9 +9,12 @@ use ark_ff::{Field, MontFp};
 
 use crate::{Fq, Fr};
 
+pub mod banderwagon;
 #[cfg(test)]
 mod tests;
 
+pub type Elligator2Map = ark_curve_utils::elligator2::Elligator2Map<BandersnatchConfig>;
+
 pub type EdwardsAffine = Affine<BandersnatchConfig>;
 pub type EdwardsProjective = Projective<BandersnatchConfig>;
 
@@ -100,6 +105,11 @@ impl MontCurveConfig for BandersnatchConfig {
     type TECurveConfig = BandersnatchConfig;
 }
 
+impl Elligator2Config for BandersnatchConfig {
+    /// Z = 5, the non-square of smallest absolute value in the base field.
+    const Z: Fq = MontFp!("5");
+}
+
 // The TE form generator is generated following Zcash's fashion:
 //  "The generators of G1 and G2 are computed by finding the lexicographically
 //   smallest valid x-coordinate, and its lexicographically smallest
@@ -137,3 +147,9 @@ impl SWCurveConfig for BandersnatchConfig {
     /// generators
     const GENERATOR: SWAffine = SWAffine::new_unchecked(SW_GENERATOR_X, SW_GENERATOR_Y);
 }
+
+impl SWUConfig for BandersnatchConfig {
+    /// ZETA = 10, as found by the procedure of appendix H.2 of RFC 9380 for
+    /// the short Weierstrass form.
+    const ZETA: Fq = MontFp!("10");
+}
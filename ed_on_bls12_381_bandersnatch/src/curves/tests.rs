@@ -1,4 +1,336 @@
 use crate::*;
 use ark_algebra_test_templates::*;
+use ark_ff::MontFp;
 
 test_group!(te; EdwardsProjective; te);
+
+#[test]
+fn test_elligator2_map_to_curve() {
+    use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+    use ark_ff::{UniformRand, Zero};
+
+    let mut rng = ark_std::test_rng();
+    let mapper = Elligator2Map::new().unwrap();
+    assert!(mapper.map_to_curve(Fq::zero()).unwrap().is_on_curve());
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        // Elligator 2 only depends on u^2.
+        assert_eq!(mapper.map_to_curve(-u).unwrap(), p);
+    }
+}
+
+/// (u, x, y), where (x, y) is the image of u by the Elligator 2 map with Z = 5.
+/// They were computed by
+/// `../ed_on_bls12_381/scripts/hash_to_curve_vectors.py bandersnatch`,
+/// an implementation of RFC 9380 independent of arkworks. They agree with the
+/// `Elligator2Map` of `ark-ec` 0.5.
+const ELLIGATOR2_VECTORS: [(Fq, Fq, Fq); 6] = [
+    (
+        MontFp!("1"),
+        MontFp!("9912268024397031058474559505647885744741350272360411759620219562492032642667"),
+        MontFp!("30151718732961596270388562278365022222389409609732653536024559465169123119074"),
+    ),
+    (
+        MontFp!("2"),
+        MontFp!("35007252406102166008923831300404286182760571370594127518679785885219032607052"),
+        MontFp!("44562938834782391261523585290984768181125624854576642468993922515408611778311"),
+    ),
+    (
+        MontFp!("3"),
+        MontFp!("11372023639509180561312305960954553826038212568448264052879041847615243855061"),
+        MontFp!("32059211200700009100005326382505102498655174780236073373502699006853491339652"),
+    ),
+    (
+        MontFp!("5"),
+        MontFp!("35362348350232086824525819391408768217799984345162544589480509138549071376224"),
+        MontFp!("6317966728064488285576111885275736188972017256871309323100297940499732377705"),
+    ),
+    (
+        MontFp!("1234567"),
+        MontFp!("6588434075278233045856621751523322829119943103872812536795919556077009833191"),
+        MontFp!("30444049476369368190551259523101455609689650245000238963637507961762932295749"),
+    ),
+    (
+        MontFp!("-7"),
+        MontFp!("44375650525261526953324346380072999536172116710824821463924523608444210007394"),
+        MontFp!("23905217694955708642983735158881327221565464961893390276947494348288585667031"),
+    ),
+];
+
+#[test]
+fn test_elligator2_vectors() {
+    use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+    use ark_ff::Zero;
+
+    let mapper = Elligator2Map::new().unwrap();
+    // The exceptional case x2 = 0 is mapped to the identity.
+    assert!(mapper.map_to_curve(Fq::zero()).unwrap().is_zero());
+    for (u, x, y) in ELLIGATOR2_VECTORS {
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        assert_eq!(p, EdwardsAffine::new_unchecked(x, y));
+    }
+}
+
+#[test]
+fn test_swu_map_to_curve() {
+    use ark_ec::hashing::{curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurve};
+    use ark_ff::UniformRand;
+
+    let mut rng = ark_std::test_rng();
+    let mapper = SWUMap::<SWConfig>::new().unwrap();
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        // The sign of the y-coordinate follows the one of u.
+        assert_eq!(mapper.map_to_curve(-u).unwrap(), -p);
+    }
+}
+
+/// (u, x, y), where (x, y) is the image of u by the simplified SWU map of the
+/// short Weierstrass form with Z = 10, computed by the same script. u = 0
+/// is the exceptional case of the map.
+const SWU_VECTORS: [(Fq, Fq, Fq); 7] = [
+    (
+        MontFp!("0"),
+        MontFp!("8156090259662534555879781786443714310082671353635332059771885841837233947321"),
+        MontFp!("33246348854325405278685738116900622002128525453174101334968878887679221547388"),
+    ),
+    (
+        MontFp!("1"),
+        MontFp!("46403873718715073385675746856888071745315308841884032961419950586764429439020"),
+        MontFp!("33199114197060936828661268187835400041623988939765934852373151513005478259485"),
+    ),
+    (
+        MontFp!("2"),
+        MontFp!("46601474567801116927194036781880714134562486959731724736453673611100427728268"),
+        MontFp!("46144314357960624532825103637076584236889228493193168326901084006005941676280"),
+    ),
+    (
+        MontFp!("3"),
+        MontFp!("221235574995466225612480079041226364216690472006422877780727373951980734253"),
+        MontFp!("39873221872109148250770551425595596002232317123597192617250533266052323448711"),
+    ),
+    (
+        MontFp!("5"),
+        MontFp!("6587034394258170046681956918159281970579176790078698239431632966615843939821"),
+        MontFp!("45206674957894811718082895776553582963301462363153950526307336262939123736731"),
+    ),
+    (
+        MontFp!("1234567"),
+        MontFp!("26642290124955275848615823486776256135915372132195043560827629165445331158541"),
+        MontFp!("42856135347459444940938145129405792632109768717661645767141319485892827589301"),
+    ),
+    (
+        MontFp!("-7"),
+        MontFp!("21137625400376995009237365526918922758879638452704644657743046176138200916179"),
+        MontFp!("41439491719840307430922358557100663344554412359709088614184702511987051385680"),
+    ),
+];
+
+#[test]
+fn test_swu_vectors() {
+    use ark_ec::hashing::{curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurve};
+
+    let mapper = SWUMap::<SWConfig>::new().unwrap();
+    for (u, x, y) in SWU_VECTORS {
+        let p = mapper.map_to_curve(u).unwrap();
+        assert!(p.is_on_curve());
+        assert_eq!(p, SWAffine::new_unchecked(x, y));
+    }
+}
+
+/// The outputs for "abc" of `MapToCurveBasedHasher` with
+/// `XMDFieldHasher<Sha256>` and the DST "domain", with the Elligator 2 map to
+/// the twisted Edwards form and with the SWU map to the short Weierstrass
+/// form, computed by the same script. The cofactor is cleared by
+/// multiplying by it.
+const HASH_ABC_TE: (Fq, Fq) = (
+    MontFp!("2497957321030404965213533100044228857985079410522392345473325671177030502381"),
+    MontFp!("37050318590479808493856325807277176233326549935348199283256278100123796412404"),
+);
+const HASH_ABC_SW: (Fq, Fq) = (
+    MontFp!("19409470568611573492929103054566027453244470609728444310054613111491791597679"),
+    MontFp!("19275190480366764078436119660703065357547128664318317373144389076128544693952"),
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    use ark_curve_utils::field_hashers::XMDFieldHasher;
+    use ark_ec::hashing::{
+        curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
+    };
+    use sha2::Sha256;
+
+    let te_hasher =
+        MapToCurveBasedHasher::<EdwardsProjective, XMDFieldHasher<Sha256>, Elligator2Map>::new(
+            b"domain",
+        )
+        .unwrap();
+    let sw_hasher =
+        MapToCurveBasedHasher::<SWProjective, XMDFieldHasher<Sha256>, SWUMap<SWConfig>>::new(
+            b"domain",
+        )
+        .unwrap();
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+        let p = te_hasher.hash(msg).unwrap();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+
+        let p = sw_hasher.hash(msg).unwrap();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    let (x, y) = HASH_ABC_TE;
+    assert_eq!(te_hasher.hash(b"abc").unwrap(), EdwardsAffine::new(x, y));
+    let (x, y) = HASH_ABC_SW;
+    assert_eq!(sw_hasher.hash(b"abc").unwrap(), SWAffine::new(x, y));
+}
+
+/// The encodings of the generator of Banderwagon doubled 0 to 15 times, from
+/// the `serialize_gen` test of `banderwagon/src/element.rs` in rust-verkle,
+/// which go-ipa checks as `TestEncodingFixedVectors`.
+const BANDERWAGON_DOUBLINGS: [&str; 16] = [
+    "4a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9",
+    "43aa74ef706605705989e8fd38df46873b7eae5921fbed115ac9d937399ce4d5",
+    "5e5f550494159f38aa54d2ed7f11a7e93e4968617990445cc93ac8e59808c126",
+    "0e7e3748db7c5c999a7bcd93d71d671f1f40090423792266f94cb27ca43fce5c",
+    "14ddaa48820cb6523b9ae5fe9fe257cbbd1f3d598a28e670a40da5d1159d864a",
+    "6989d1c82b2d05c74b62fb0fbdf8843adae62ff720d370e209a7b84e14548a7d",
+    "26b8df6fa414bf348a3dc780ea53b70303ce49f3369212dec6fbe4b349b832bf",
+    "37e46072db18f038f2cc7d3d5b5d1374c0eb86ca46f869d6a95fc2fb092c0d35",
+    "2c1ce64f26e1c772282a6633fac7ca73067ae820637ce348bb2c8477d228dc7d",
+    "297ab0f5a8336a7a4e2657ad7a33a66e360fb6e50812d4be3326fab73d6cee07",
+    "5b285811efa7a965bd6ef5632151ebf399115fcc8f5b9b8083415ce533cc39ce",
+    "1f939fa2fd457b3effb82b25d3fe8ab965f54015f108f8c09d67e696294ab626",
+    "3088dcb4d3f4bacd706487648b239e0be3072ed2059d981fe04ce6525af6f1b8",
+    "35fbc386a16d0227ff8673bc3760ad6b11009f749bb82d4facaea67f58fc60ed",
+    "00f29b4f3255e318438f0a31e058e4c081085426adb0479f14c64985d0b956e0",
+    "3fa4384b2fa0ecc3c0582223602921daaa893a97b64bdf94dcaa504e8b7b9e5f",
+];
+
+#[test]
+fn test_banderwagon_serialization_vectors() {
+    use ark_ec::Group;
+    use banderwagon::Banderwagon;
+
+    let mut p = Banderwagon::generator();
+    for expected in BANDERWAGON_DOUBLINGS {
+        assert_eq!(hex::encode(p.encode()), expected);
+        let bytes = hex::decode(expected).unwrap().try_into().unwrap();
+        assert_eq!(Banderwagon::decode(&bytes).unwrap(), p);
+        p.double_in_place();
+    }
+}
+
+/// `map_to_scalar_field` of the generator of Banderwagon, serialized in
+/// little-endian, from the `consistent_group_to_field` test of
+/// `banderwagon/src/element.rs` in rust-verkle.
+const BANDERWAGON_GENERATOR_TO_FIELD: &str =
+    "d1e7de2aaea9603d5bc6c208d319596376556ecd8336671ba7670c2139772d14";
+
+#[test]
+fn test_banderwagon_map_to_field_vectors() {
+    use ark_ec::Group;
+    use ark_serialize::CanonicalSerialize;
+    use banderwagon::Banderwagon;
+
+    let g = Banderwagon::generator();
+    let mut bytes = Vec::new();
+    g.map_to_scalar_field()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert_eq!(hex::encode(bytes), BANDERWAGON_GENERATOR_TO_FIELD);
+    // Negation maps (x, y) to (-x, y).
+    assert_eq!((-g).map_to_field(), -g.map_to_field());
+}
+
+#[test]
+fn test_banderwagon_two_torsion() {
+    use ark_ff::{BigInteger, PrimeField, Zero};
+    use ark_std::UniformRand;
+    use banderwagon::Banderwagon;
+
+    // The all-zero encoding decodes to the representative (0, -1) of the
+    // identity, and adding it to a representative yields the other one.
+    let t = Banderwagon::decode(&[0u8; banderwagon::ENCODED_SIZE]).unwrap();
+    assert!(t.is_zero());
+    assert_eq!(t, Banderwagon::zero());
+
+    let mut rng = ark_std::test_rng();
+    for _ in 0..10 {
+        let p = Banderwagon::rand(&mut rng);
+        assert_eq!(p + t, p);
+        assert_eq!((p + t).encode(), p.encode());
+        assert_eq!((p + t).map_to_field(), p.map_to_field());
+        assert_eq!((-p).map_to_field(), -p.map_to_field());
+        assert_eq!(
+            p.map_to_scalar_field(),
+            Fr::from_le_bytes_mod_order(&p.map_to_field().into_bigint().to_bytes_le())
+        );
+    }
+}
+
+#[test]
+fn test_banderwagon_invalid_encodings() {
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_serialize::{CanonicalDeserialize, Valid};
+    use banderwagon::Banderwagon;
+
+    let encode_x = |x: u64| -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&x.to_be_bytes());
+        bytes
+    };
+
+    // x = 1 is the x-coordinate of an element.
+    assert!(Banderwagon::decode(&encode_x(1)).is_ok());
+
+    // x = q is not canonical, and x = 2 is not the x-coordinate of a point.
+    let q = Fq::MODULUS.to_bytes_be().try_into().unwrap();
+    for bytes in [q, encode_x(2)] {
+        assert!(Banderwagon::decode(&bytes).is_err());
+        assert!(Banderwagon::deserialize_compressed_unchecked(bytes.as_slice()).is_err());
+    }
+
+    // x = 7 is the x-coordinate of a point outside of the subgroup.
+    let bytes = encode_x(7);
+    assert!(Banderwagon::decode(&bytes).is_err());
+    assert!(Banderwagon::deserialize_compressed(bytes.as_slice()).is_err());
+    let p = Banderwagon::deserialize_compressed_unchecked(bytes.as_slice()).unwrap();
+    assert!(!p.subgroup_check());
+    assert!(p.check().is_err());
+}
+
+#[test]
+fn test_banderwagon_group() {
+    use ark_ec::Group;
+    use ark_ff::{UniformRand, Zero};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::vec::Vec;
+    use banderwagon::Banderwagon;
+
+    let mut rng = ark_std::test_rng();
+    let g = Banderwagon::generator();
+    for _ in 0..10 {
+        let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        assert_eq!(g * a + g * b, g * (a + b));
+        assert_eq!(g * a - g * b, g * (a - b));
+        assert!((g * a + -(g * a)).is_zero());
+
+        let p = Banderwagon::rand(&mut rng);
+        assert!(p.subgroup_check());
+        assert_eq!(Banderwagon::decode(&p.encode()).unwrap(), p);
+        let mut bytes = Vec::new();
+        p.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), banderwagon::ENCODED_SIZE);
+        assert_eq!(
+            Banderwagon::deserialize_compressed(bytes.as_slice()).unwrap(),
+            p
+        );
+    }
+}
@@ -1,11 +1,19 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+};
 use ark_ec::{
+    hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
+    AffineRepr,
 };
-use ark_ff::{Field, MontFp, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
+mod swu_iso;
 #[cfg(test)]
 mod tests;
 
@@ -40,6 +48,76 @@ impl SWCurveConfig for PallasConfig {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_projective(*p, s),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_affine(*base, s).into(),
+            None => double_and_add(&base.into_group(), scalar),
+        }
+    }
+
+    /// The halves of the GLV decomposition of the scalars have at most 128 bits.
+    #[inline]
+    fn msm(bases: &[Affine], scalars: &[Fr]) -> Result<Projective, usize> {
+        (bases.len() == scalars.len())
+            .then(|| glv::glv_msm(bases, scalars, 128))
+            .ok_or(bases.len().min(scalars.len()))
+    }
+}
+
+impl GLVConfig for PallasConfig {
+    /// ENDO_COEFFS = [BETA], where BETA is a non-trivial cube root of unity in Fq:
+    /// BETA = 8503465768106391777493614032514048814691664078728891710322960303815233784505
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "8503465768106391777493614032514048814691664078728891710322960303815233784505"
+    )];
+
+    /// LAMBDA is the cube root of unity in Fr such that phi(P) = [LAMBDA]P:
+    /// LAMBDA = 2942865608506852014473558576493638302197734138389222805617480874486368177743
+    const LAMBDA: Self::ScalarField =
+        MontFp!("2942865608506852014473558576493638302197734138389222805617480874486368177743");
+
+    /// SCALAR_DECOMP_COEFFS = [(a1, b1), (a2, b2)], a reduced basis of the
+    /// lattice {(a, b) : a + b * LAMBDA = 0 mod r}, with a1 * b2 - a2 * b1 = r
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("98231058071186745657228807397848383489")),
+        (false, BigInt!("98231058071100081932162823354453065728")),
+        (true, BigInt!("98231058071100081932162823354453065728")),
+        (true, BigInt!("196462116142286827589391630752301449217")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+}
+
+// Parameters of the Orchard `hash_to_curve` for Pallas. It hashes to field
+// elements with `ark_curve_utils::field_hashers::XMDFieldHasher<Blake2b512, 256>`
+// and the DST `<domain>-pallas_XMD:BLAKE2b_SSWU_RO_`.
+impl WBConfig for PallasConfig {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_PALLAS;
 }
 
 /// G_GENERATOR_X = -1
@@ -0,0 +1,77 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_glv};
+use ark_ec::{
+    hashing::{
+        curve_maps::{swu::SWUMap, wb::WBMap},
+        map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+        HashToCurve,
+    },
+    short_weierstrass::Affine,
+};
+use ark_ff::{Field, One, PrimeField, Zero};
+use blake2::Blake2b512;
+
+use super::swu_iso::SwuIsoConfig;
+use crate::{Fq, PallasConfig, Projective};
+
+test_group!(g1; Projective; sw);
+test_glv!(g1_glv; PallasConfig; glv);
+
+// The known answers below are copied verbatim from the tests of
+// `src/pallas.rs` of https://github.com/zcash/pasta_curves at commit
+// 7e3fc6a4919f6462a32b79dd226cb2587b7961eb (release 0.5.1), which give the
+// Jacobian coordinates `(X, Y, Z)` of the points. `pasta_curves` hashes to
+// field elements with expand_message_xmd instantiated with BLAKE2b-512 and
+// with the DST `<domain>-pallas_XMD:BLAKE2b_SSWU_RO_`.
+fn jacobian_to_affine<P>(x: &str, y: &str, z: &str) -> Affine<P>
+where
+    P: ark_ec::short_weierstrass::SWCurveConfig<BaseField = Fq>,
+{
+    let read = |s: &str| Fq::from_be_bytes_mod_order(&hex::decode(&s[2..]).unwrap());
+    let z_inv = read(z).inverse().unwrap();
+    let z_inv2 = z_inv.square();
+    Affine::new(read(x) * z_inv2, read(y) * z_inv2 * z_inv)
+}
+
+#[test]
+fn test_map_to_curve_simple_swu() {
+    let map = SWUMap::<SwuIsoConfig>::new().unwrap();
+
+    // The zero input is a special case.
+    assert_eq!(
+        map.map_to_curve(Fq::zero()).unwrap(),
+        jacobian_to_affine(
+            "0x28c1a6a534f56c52e25295b339129a8af5f42525dea727f485ca3433519b096e",
+            "0x3bfc658bee6653c63c7d7f0927083fd315d29c270207b7c7084fa1ee6ac5ae8d",
+            "0x054b3ba10416dc104157b1318534a19d5d115472da7d746f8a5f250cd8cdef36",
+        )
+    );
+    assert_eq!(
+        map.map_to_curve(Fq::one()).unwrap(),
+        jacobian_to_affine(
+            "0x010cba5957e876534af5e967c026a1856d64b071068280837913b9a5a3561505",
+            "0x062fc61f9cd3118e7d6e65a065ebf46a547514d6b08078e976fa6d515dcc9c81",
+            "0x3f86cb8c311250c3101c4e523e7793605ccff5623de1753a7c75bc9a29a73688",
+        )
+    );
+}
+
+#[test]
+fn test_hash_to_curve() {
+    // The first map_to_curve_simple_swu takes the gx1 square branch and the
+    // second the gx1 non-square branch (opposite to the Vesta vector).
+    let hasher = MapToCurveBasedHasher::<
+        Projective,
+        XMDFieldHasher<Blake2b512, 256>,
+        WBMap<PallasConfig>,
+    >::new(b"z.cash:test-pallas_XMD:BLAKE2b_SSWU_RO_")
+    .unwrap();
+    assert_eq!(
+        hasher.hash(b"Trans rights now!").unwrap(),
+        jacobian_to_affine(
+            "0x36a6e3a9c50b7b6540cb002c977c82f37f8a875fb51eb35327ee1452e6ce7947",
+            "0x01da3b4403d73252f2d7e9c19bc23dc6a080f2d02f8262fca4f7e3d756ac6a7c",
+            "0x1d48103df8fcbb70d1809c1806c95651dd884a559fec0549658537ce9d94bed9",
+        )
+    );
+}
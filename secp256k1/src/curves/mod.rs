@@ -1,11 +1,21 @@
+use ark_curve_utils::{
+    glv,
+    scalar_mul::{double_and_add, scalar_field_element},
+};
 use ark_ec::{
+    hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
+    AffineRepr,
 };
-use ark_ff::{Field, MontFp, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
+pub mod sec1;
+mod swu_iso;
+
 #[cfg(test)]
 mod tests;
 
@@ -41,6 +51,76 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_projective(*p, s),
+            None => double_and_add(p, scalar),
+        }
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        match scalar_field_element(scalar) {
+            Some(s) => GLVConfig::glv_mul_affine(*base, s).into(),
+            None => double_and_add(&base.into_group(), scalar),
+        }
+    }
+
+    /// The halves of the GLV decomposition of the scalars have at most 129 bits.
+    #[inline]
+    fn msm(bases: &[Affine], scalars: &[Fr]) -> Result<Projective, usize> {
+        (bases.len() == scalars.len())
+            .then(|| glv::glv_msm(bases, scalars, 129))
+            .ok_or(bases.len().min(scalars.len()))
+    }
+}
+
+impl GLVConfig for Config {
+    /// ENDO_COEFFS = [BETA], where BETA is a non-trivial cube root of unity in Fq:
+    /// BETA = 55594575648329892869085402983802832744385952214688224221778511981742606582254
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254"
+    )];
+
+    /// LAMBDA is the cube root of unity in Fr such that phi(P) = [LAMBDA]P:
+    /// LAMBDA = 37718080363155996902926221483475020450927657555482586988616620542887997980018
+    const LAMBDA: Self::ScalarField =
+        MontFp!("37718080363155996902926221483475020450927657555482586988616620542887997980018");
+
+    /// SCALAR_DECOMP_COEFFS = [(a1, b1), (a2, b2)], a reduced basis of the
+    /// lattice {(a, b) : a + b * LAMBDA = 0 mod r}, with a1 * b2 - a2 * b1 = r
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709077")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468248")),
+        (true, BigInt!("64502973549206556628585045361533709077")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        // phi(x, y) = (BETA * x, y)
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+}
+
+/// The map of the `secp256k1_XMD:SHA-256_SSWU_RO_` suite of section 8.7 of
+/// RFC 9380. The suite hashes to field elements with
+/// `ark_curve_utils::field_hashers::XMDFieldHasher<Sha256>`.
+impl WBConfig for Config {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_SECP256K1;
 }
 
 /// G_GENERATOR_X =
@@ -0,0 +1,36 @@
+//! The point and scalar encodings of [SEC 1], as described in
+//! [`ark_curve_utils::sec1`]. As the cofactor is 1, every decoded point is in
+//! the prime-order group.
+//!
+//! [SEC 1]: https://www.secg.org/sec1-v2.pdf
+
+use ark_curve_utils::sec1;
+use ark_serialize::{Compress, SerializationError};
+use ark_std::vec::Vec;
+
+use crate::{Affine, Config, Fr};
+
+/// The size of an encoded field element or scalar.
+pub const FIELD_ENCODED_SIZE: usize = 32;
+pub const COMPRESSED_POINT_SIZE: usize = 1 + FIELD_ENCODED_SIZE;
+pub const UNCOMPRESSED_POINT_SIZE: usize = 1 + 2 * FIELD_ENCODED_SIZE;
+
+/// Encodes `p`, which is the single byte `0x00` for the point at infinity.
+pub fn encode_point(p: &Affine, compress: Compress) -> Vec<u8> {
+    sec1::encode_point::<Config, FIELD_ENCODED_SIZE>(p, compress)
+}
+
+/// Decodes a compressed, uncompressed or infinity point encoding.
+pub fn decode_point(bytes: &[u8]) -> Result<Affine, SerializationError> {
+    sec1::decode_point::<Config, FIELD_ENCODED_SIZE>(bytes)
+}
+
+/// Encodes `s` as a 32-byte big-endian integer.
+pub fn encode_scalar(s: Fr) -> [u8; FIELD_ENCODED_SIZE] {
+    sec1::encode_field_element(s)
+}
+
+/// Decodes a 32-byte big-endian integer, which must be smaller than n.
+pub fn decode_scalar(bytes: &[u8; FIELD_ENCODED_SIZE]) -> Result<Fr, SerializationError> {
+    sec1::decode_field_element(bytes)
+}
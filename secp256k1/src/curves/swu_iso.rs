@@ -0,0 +1,83 @@
+use crate::{Config, Fq, Fr};
+use ark_ec::{
+    hashing::curve_maps::{swu::SWUConfig, wb::IsogenyMap},
+    models::{
+        short_weierstrass::{Affine, SWCurveConfig},
+        CurveConfig,
+    },
+};
+use ark_ff::{Field, MontFp};
+
+type IsoAffine = Affine<SwuIsoConfig>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SwuIsoConfig;
+
+impl CurveConfig for SwuIsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+// https://www.rfc-editor.org/rfc/rfc9380.html
+// Hashing to Elliptic Curves
+// 8.7.  secp256k1
+// secp256k1_XMD:SHA-256_SSWU_RO_ is defined as follows:
+// * E': y'^2 = x'^3 + A' * x' + B', where
+//      -  A' = 0x3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533
+//      -  B' = 1771
+// * Z: -11
+impl SWCurveConfig for SwuIsoConfig {
+    const COEFF_A: Fq =
+        MontFp!("28734576633528757162648956269730739219262246272443394170905244663053633733939");
+
+    const COEFF_B: Fq = MontFp!("1771");
+
+    const GENERATOR: IsoAffine = IsoAffine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+}
+
+/// Lexicographically smallest, valid x-coordinate of a point on the curve,
+/// with the smaller of its two y-coordinates.
+const GENERATOR_X: Fq = MontFp!("0");
+const GENERATOR_Y: Fq =
+    MontFp!("55499581271723525644648544548655918908597142866156965151692875555195126288698");
+
+impl SWUConfig for SwuIsoConfig {
+    // ZETA = -11 as per RFC 9380.
+    const ZETA: Fq = MontFp!("-11");
+}
+
+// Constants from Appendix E.1 of RFC 9380.
+pub const ISOGENY_MAP_TO_SECP256K1: IsogenyMap<'_, SwuIsoConfig, Config> = IsogenyMap {
+    x_map_numerator: &[
+        MontFp!("64328938465175664124206102782604393251816658147578091133031991115504908150983"),
+        MontFp!("3540463234204664767867377763959255381561641196938647754971861192896365225345"),
+        MontFp!("37676595701789655284650173187508961899444205326770530105295841645151729341026"),
+        MontFp!("64328938465175664124206102782604393251816658147578091133031991115504908150924"),
+    ],
+
+    x_map_denominator: &[
+        MontFp!("95592507323525948732419199626899895302164312317343489384240252208201861084315"),
+        MontFp!("107505182841474506714709588670204841388457878609653642868747406790547894725908"),
+        MontFp!("1"),
+    ],
+
+    y_map_numerator: &[
+        MontFp!("34308767181427020866243254817389009734302217678708315270950395261602617680444"),
+        MontFp!("90176424683627901097894375140309208301239340832535417794535213712559228940707"),
+        MontFp!("18838297850894827642325086593754480949722102663385265052647920822575864670513"),
+        MontFp!("21442979488391888041402034260868131083938886049192697044343997038501636050308"),
+    ],
+
+    y_map_denominator: &[
+        MontFp!("115792089237316195423570985008687907853269984665640564039457584007908834670907"),
+        MontFp!("55193343495945455350115628863323870199952967620749340073805588608787913909619"),
+        MontFp!("45465685024895564648493397996619354229416833248839900263663526177913007417199"),
+        MontFp!("1"),
+    ],
+};
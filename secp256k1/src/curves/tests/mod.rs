@@ -0,0 +1,67 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_glv, test_h2c_xmd, test_sec1};
+use ark_ec::hashing::{
+    curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
+};
+use ark_serialize::Compress;
+use sha2::Sha256;
+
+use crate::{sec1, Config, Projective};
+
+test_group!(g1; Projective; sw);
+test_glv!(g1_glv; Config; glv);
+
+test_h2c_xmd!(
+    h2c;
+    "./src/curves/tests/secp256k1_XMD-SHA-256_SSWU_RO_.json";
+    Projective;
+    XMDFieldHasher<Sha256>;
+    WBMap<Config>
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    let hasher = MapToCurveBasedHasher::<Projective, XMDFieldHasher<Sha256>, WBMap<Config>>::new(
+        b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_",
+    )
+    .unwrap();
+    let p = hasher.hash(b"abc").unwrap();
+    assert_eq!(
+        hex::encode(sec1::encode_point(&p, Compress::No)),
+        "04\
+         3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b\
+         7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6"
+    );
+}
+
+/// Private keys, and the compressed and uncompressed SEC1 encodings of the
+/// corresponding public keys.
+const SEC1_KEYS: [(&str, &str, &str); 5] = [
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        "04c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee51ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        "04f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+    ),
+    (
+        "a625b4efa78e636106cd2d9d2255b4ed81a79557198a74710dfdbdca0c2ac94c",
+        "03a87cf0dfb06e223ea219d036b8564bcfeb32447e20cfac288557bddbc971cdea",
+        "04a87cf0dfb06e223ea219d036b8564bcfeb32447e20cfac288557bddbc971cdeaafacd5ff5a23805df7f577d342b87310968ca6580a5c3c38b2ddb8fcfc526c4d",
+    ),
+    (
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+    ),
+];
+
+test_sec1!(sec1_encoding; sec1; Config; SEC1_KEYS);
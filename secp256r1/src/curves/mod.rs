@@ -1,4 +1,5 @@
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -6,6 +7,8 @@ use ark_ff::{Field, MontFp};
 
 use crate::{fq::Fq, fr::Fr};
 
+pub mod sec1;
+
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +42,15 @@ impl SWCurveConfig for Config {
     const GENERATOR: Affine = Affine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
 }
 
+/// The map of the `P256_XMD:SHA-256_SSWU_RO_` and `P256_XMD:SHA-256_SSWU_NU_`
+/// suites of RFC 9380. The curve has a non-zero A, so the simplified SWU map
+/// is applied to it directly, without an isogeny. The suites hash to field
+/// elements with `ark_curve_utils::field_hashers::XMDFieldHasher<Sha256>`.
+impl SWUConfig for Config {
+    /// ZETA = -10
+    const ZETA: Fq = MontFp!("-10");
+}
+
 /// G_GENERATOR_X =
 /// 0x6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
 pub const G_GENERATOR_X: Fq =
@@ -0,0 +1,73 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_h2c_xmd, test_sec1};
+use ark_ec::hashing::{
+    curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
+};
+use ark_serialize::Compress;
+use sha2::Sha256;
+
+use crate::{sec1, Config, Projective};
+
+test_group!(g1; Projective; sw);
+
+test_h2c_xmd!(
+    h2c_ro;
+    "./src/curves/tests/p256_XMD-SHA-256_SSWU_RO_.json";
+    Projective;
+    XMDFieldHasher<Sha256>;
+    SWUMap<Config>
+);
+test_h2c_xmd!(
+    h2c_nu;
+    "./src/curves/tests/p256_XMD-SHA-256_SSWU_NU_.json";
+    Projective;
+    XMDFieldHasher<Sha256>;
+    SWUMap<Config>
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    let hasher = MapToCurveBasedHasher::<Projective, XMDFieldHasher<Sha256>, SWUMap<Config>>::new(
+        b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_",
+    )
+    .unwrap();
+    let p = hasher.hash(b"abc").unwrap();
+    assert_eq!(
+        hex::encode(sec1::encode_point(&p, Compress::No)),
+        "04\
+         0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f\
+         5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"
+    );
+}
+
+/// Private keys, and the compressed and uncompressed SEC1 encodings of the
+/// corresponding public keys.
+const SEC1_KEYS: [(&str, &str, &str); 5] = [
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "037cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+        "047cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997807775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "025ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+        "045ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+    ),
+    (
+        "ae949c13f174ec8ada2ad4415251ece71ed8fb6407d58434bf02f7b6e9c5c6ec",
+        "03240f968c3b17575928c7da3158d7d0b8d7df4ced5d7d08c5bc97df87ae3a0b64",
+        "04240f968c3b17575928c7da3158d7d0b8d7df4ced5d7d08c5bc97df87ae3a0b64b2cff41c21d4e92c87aac7663d73f8f43c430203c3fd0d970fe7acf2a065ad5f",
+    ),
+    (
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550",
+        "026b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a",
+    ),
+];
+
+test_sec1!(sec1_encoding; sec1; Config; SEC1_KEYS);
@@ -1,4 +1,5 @@
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -6,6 +7,8 @@ use ark_ff::{Field, MontFp};
 
 use crate::{fq::Fq, fr::Fr};
 
+pub mod sec1;
+
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +42,15 @@ impl SWCurveConfig for Config {
     const GENERATOR: Affine = Affine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
 }
 
+/// The map of the `P384_XMD:SHA-384_SSWU_RO_` and `P384_XMD:SHA-384_SSWU_NU_`
+/// suites of RFC 9380. The curve has a non-zero A, so the simplified SWU map
+/// is applied to it directly, without an isogeny. The suites hash to field
+/// elements with `ark_curve_utils::field_hashers::XMDFieldHasher<Sha384, 192>`.
+impl SWUConfig for Config {
+    /// ZETA = -12
+    const ZETA: Fq = MontFp!("-12");
+}
+
 /// G_GENERATOR_X =
 /// 26247035095799689268623156744566981891852923491109213387815615900925518854738050089022388053975719786650872476732087
 pub const G_GENERATOR_X: Fq =
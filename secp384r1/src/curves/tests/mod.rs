@@ -0,0 +1,74 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_h2c_xmd, test_sec1};
+use ark_ec::hashing::{
+    curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
+};
+use ark_serialize::Compress;
+use sha2::Sha384;
+
+use crate::{sec1, Config, Projective};
+
+test_group!(g1; Projective; sw);
+
+test_h2c_xmd!(
+    h2c_ro;
+    "./src/curves/tests/p384_XMD-SHA-384_SSWU_RO_.json";
+    Projective;
+    XMDFieldHasher<Sha384, 192>;
+    SWUMap<Config>
+);
+test_h2c_xmd!(
+    h2c_nu;
+    "./src/curves/tests/p384_XMD-SHA-384_SSWU_NU_.json";
+    Projective;
+    XMDFieldHasher<Sha384, 192>;
+    SWUMap<Config>
+);
+
+#[test]
+fn test_hash_arbitrary_string_to_curve() {
+    let hasher =
+        MapToCurveBasedHasher::<Projective, XMDFieldHasher<Sha384, 192>, SWUMap<Config>>::new(
+            b"QUUX-V01-CS02-with-P384_XMD:SHA-384_SSWU_RO_",
+        )
+        .unwrap();
+    let p = hasher.hash(b"abc").unwrap();
+    assert_eq!(
+        hex::encode(sec1::encode_point(&p, Compress::No)),
+        "04\
+         e02fc1a5f44a7519419dd314e29863f30df55a514da2d655775a81d413003c4d4e7fd59af0826dfaad4200ac6f60abe1\
+         01f638d04d98677d65bef99aef1a12a70a4cbb9270ec55248c04530d8bc1f8f90f8a6a859a7c1f1ddccedf8f96d675f6"
+    );
+}
+
+/// Private keys, and the compressed and uncompressed SEC1 encodings of the
+/// corresponding public keys.
+const SEC1_KEYS: [(&str, &str, &str); 5] = [
+    (
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        "03aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+        "04aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab73617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+    ),
+    (
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+        "0208d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+        "0408d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df618e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+    ),
+    (
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003",
+        "03077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+        "04077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1",
+    ),
+    (
+        "c65ab5215dbae8c882b83fbae691931484b52feba69203c508ff416b48bba36e90297b96765cc29491b3dddc883ebc16",
+        "02031bb3fdb430d6b7bb581b7d7d1f6f01a7f5906e65d560c22ead9ff8b0fa3730eefe3a2072718d761ede874ba5d54f1e",
+        "04031bb3fdb430d6b7bb581b7d7d1f6f01a7f5906e65d560c22ead9ff8b0fa3730eefe3a2072718d761ede874ba5d54f1ecc9f1fb3bde18ea996104f6f90e4edc6d0247907206b5efe46593d939bc727554744ce6f7654b70d063a1608c8af57fa",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52972",
+        "02aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+        "04aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7c9e821b569d9d390a26167406d6d23d6070be242d765eb831625ceec4a0f473ef59f4e30e2817e6285bce2846f15f1a0",
+    ),
+];
+
+test_sec1!(sec1_encoding; sec1; Config; SEC1_KEYS);
@@ -1,4 +1,39 @@
-use crate::Projective;
 use ark_algebra_test_templates::*;
+use ark_curve_utils::{test_glv, test_sec1};
+
+use crate::{sec1, Config, Projective};
 
 test_group!(g1; Projective; sw);
+test_glv!(g1_glv; Config; glv);
+
+/// Private keys, and the compressed and uncompressed SEC1 encodings of the
+/// corresponding public keys.
+const SEC1_KEYS: [(&str, &str, &str); 5] = [
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0376c39f5585cb160eb6b06c87a2ce32e23134e45a097781a6a24288e37702eda6",
+        "0476c39f5585cb160eb6b06c87a2ce32e23134e45a097781a6a24288e37702eda63ffc646c7b2918b5dc2d265a8e82a7f7d18983d26e8dc055a4120ddad952677f",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "02c17340f13398a692e5f455d1275059e44c085f188a12ab0aae2fa282098c29c0",
+        "04c17340f13398a692e5f455d1275059e44c085f188a12ab0aae2fa282098c29c018b1dfccd9b774dd05e4f53a15cab27b781f77ffbf92d7617935bf32d9288812",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "03bfee81e7fa8480a8ee0a13d4bf21fdcf37beaa125d921fe088f339b1549819fa",
+        "04bfee81e7fa8480a8ee0a13d4bf21fdcf37beaa125d921fe088f339b1549819faeb9e37d68bf1f60965432b007419ae9c7875dd9929dde2536c905934144c5ee9",
+    ),
+    (
+        "dbeec1b66ce8ec23b0a65497a0b0221275e604780f6f23d4a5d72f6f9cdf3c65",
+        "02da35dfbe002993259e7a134b77b72207b0abef9afe36685ebc2539a3801262ba",
+        "04da35dfbe002993259e7a134b77b72207b0abef9afe36685ebc2539a3801262bac9411c7f12c8060c2c31f928510b9145d9b9f61406e66f0be7802445fe2e4894",
+    ),
+    (
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+        "0276c39f5585cb160eb6b06c87a2ce32e23134e45a097781a6a24288e37702eda6",
+        "0476c39f5585cb160eb6b06c87a2ce32e23134e45a097781a6a24288e37702eda6c0039b9384d6e74a23d2d9a5717d5806e925591440badfe61bc050b1f6e3d9c2",
+    ),
+];
+
+test_sec1!(sec1_encoding; sec1; Config; SEC1_KEYS);
@@ -0,0 +1,77 @@
+use ark_algebra_test_templates::*;
+use ark_curve_utils::{field_hashers::XMDFieldHasher, test_glv};
+use ark_ec::{
+    hashing::{
+        curve_maps::{swu::SWUMap, wb::WBMap},
+        map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+        HashToCurve,
+    },
+    short_weierstrass::Affine,
+};
+use ark_ff::{Field, One, PrimeField, Zero};
+use blake2::Blake2b512;
+
+use super::swu_iso::SwuIsoConfig;
+use crate::{Fq, Projective, VestaConfig};
+
+test_group!(g1; Projective; sw);
+test_glv!(g1_glv; VestaConfig; glv);
+
+// The known answers below are copied verbatim from the tests of
+// `src/vesta.rs` of https://github.com/zcash/pasta_curves at commit
+// 7e3fc6a4919f6462a32b79dd226cb2587b7961eb (release 0.5.1), which give the
+// Jacobian coordinates `(X, Y, Z)` of the points. `pasta_curves` hashes to
+// field elements with expand_message_xmd instantiated with BLAKE2b-512 and
+// with the DST `<domain>-vesta_XMD:BLAKE2b_SSWU_RO_`.
+fn jacobian_to_affine<P>(x: &str, y: &str, z: &str) -> Affine<P>
+where
+    P: ark_ec::short_weierstrass::SWCurveConfig<BaseField = Fq>,
+{
+    let read = |s: &str| Fq::from_be_bytes_mod_order(&hex::decode(&s[2..]).unwrap());
+    let z_inv = read(z).inverse().unwrap();
+    let z_inv2 = z_inv.square();
+    Affine::new(read(x) * z_inv2, read(y) * z_inv2 * z_inv)
+}
+
+#[test]
+fn test_map_to_curve_simple_swu() {
+    let map = SWUMap::<SwuIsoConfig>::new().unwrap();
+
+    // The zero input is a special case.
+    assert_eq!(
+        map.map_to_curve(Fq::zero()).unwrap(),
+        jacobian_to_affine(
+            "0x2ccc4c6ec2660e5644305bc52527d904d408f92407f599df8f158d50646a2e78",
+            "0x29a34381321d13d72d50b6b462bb4ea6a9e47393fa28a47227bf35bc0ee7aa59",
+            "0x0b851e9e579403a76df1100f556e1f226e5656bdf38f3bf8601d8a3a9a15890b",
+        )
+    );
+    assert_eq!(
+        map.map_to_curve(Fq::one()).unwrap(),
+        jacobian_to_affine(
+            "0x165f8b71841c5abc3d742ec13fb16f099d596b781e6f5c7d0b6682b1216a8258",
+            "0x0dadef21de74ed7337a37dd74f126a92e4df73c3a704da501e36eaf59cf03120",
+            "0x0a3d6f6c1af02bd9274cc0b80129759ce77edeef578d7de968d4a47d39026c82",
+        )
+    );
+}
+
+#[test]
+fn test_hash_to_curve() {
+    // The first map_to_curve_simple_swu takes the gx1 non-square branch and
+    // the second the gx1 square branch (opposite to the Pallas vector).
+    let hasher = MapToCurveBasedHasher::<
+        Projective,
+        XMDFieldHasher<Blake2b512, 256>,
+        WBMap<VestaConfig>,
+    >::new(b"z.cash:test-vesta_XMD:BLAKE2b_SSWU_RO_")
+    .unwrap();
+    assert_eq!(
+        hasher.hash(b"hello").unwrap(),
+        jacobian_to_affine(
+            "0x12763505036e0e1a6684b7a7d8d5afb7378cc2b191a95e34f44824a06fcbd08e",
+            "0x0256eafc0188b79bfa7c4b2b393893ddc298e90da500fa4a9aee17c2ea4240e6",
+            "0x1b58d4aa4d68c3f4d9916b77c79ff9911597a27f2ee46244e98eb9615172d2ad",
+        )
+    );
+}